@@ -1,2 +1,16 @@
 pub const ADMIN: &str = "82ppCojm3yrEKgdpH8B5AmBJTU1r1uAWXFWhxvPs9UCR";
+pub const TRD_MINT: &str = "t3DohmswhKk94PPbPYwA6ZKACyY3y5kbcqeQerAJjmV";
+pub const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+pub const COLLATERAL_MINTS: [&str; 2] = [TRD_MINT, WSOL_MINT]; // Accepted by permissionless markets
 pub const VERIFIER: &str = "42n6BHufivUKrb5Bi6tXbMZvM8NHovrDLX1Rt5w2a919";
+pub const PAYOUT_KEEPER_FEE_BPS: u64 = 10; // 0.1% of each payout cranked by a keeper
+pub const TRIGGER_KEEPER_FEE_BPS: u64 = 20; // 0.2% of the refund of an order closed by its trigger
+pub const TRIGGER_TWAP_WINDOW: i64 = 60; // Shortest window of the TWAP that fires order triggers
+pub const VOTING_MARKET_FEE: u64 = 50_000_000; // 0.05 SOL to propose a market
+pub const VOTING_MARKET_MIN_VOTES: u64 = 1000;
+pub const VOTE_PRICE: u64 = 1_000_000; // 1 TRD per vote after the free one
+pub const MARKET_CREATOR_BOND: u64 = 1_000_000_000; // 1000 TRD
+pub const MIN_SEED_LIQUIDITY: u64 = 100_000_000; // 100 TRD
+pub const MAX_CREATOR_FEE_BPS: u16 = 5000; // 50% of the market fee
+pub const VIRTUAL_LIQUIDITY: u64 = 5_000_000_000; // 5000 TRD on each side of a new market
+pub const BASE_DECIMALS: u8 = 6; // TRD decimals, the token amounts above are given with them
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use crate::{ constants::{ ADMIN, VERIFIER }, User, UserTrade, StakeV2, StakeVault };
+use crate::{ constants::{ ADMIN, VERIFIER, COLLATERAL_MINTS }, User, UserTrade, StakeV2, StakeVault, MarketBond };
 
 use anchor_lang::prelude::*;
 
@@ -12,6 +12,10 @@ pub fn is_verifier(signer: &Signer) -> anchor_lang::Result<bool> {
     Ok(Pubkey::from_str(VERIFIER).unwrap().eq(signer.key))
 }
 
+pub fn is_collateral_mint(mint: &Pubkey) -> anchor_lang::Result<bool> {
+    Ok(COLLATERAL_MINTS.contains(&mint.to_string().as_str()))
+}
+
 pub fn is_authority_for_user(user: &Account<User>, signer: &Signer) -> anchor_lang::Result<bool> {
     Ok(user.authority.eq(signer.key))
 }
@@ -47,3 +51,19 @@ pub fn is_authority_for_stake_vault(
 ) -> anchor_lang::Result<bool> {
     Ok(stake_vault.authority.eq(signer.key))
 }
+
+pub fn is_creator_for_market(
+    market_bond: &Account<MarketBond>,
+    market: &Pubkey,
+    signer: &Signer
+) -> anchor_lang::Result<bool> {
+    Ok(market_bond.market.eq(market) && market_bond.creator.eq(signer.key))
+}
+
+pub fn is_resolver_for_market(
+    market_bond: &Account<MarketBond>,
+    market: &Pubkey,
+    signer: &Signer
+) -> anchor_lang::Result<bool> {
+    Ok(market_bond.market.eq(market) && market_bond.resolver.eq(signer.key))
+}
@@ -64,4 +64,118 @@ pub enum TriadProtocolError {
 
     #[msg("Collection is full")]
     CollectionFull,
+
+    #[msg("Insufficient shares")]
+    InsufficientShares,
+
+    #[msg("Invalid order direction")]
+    InvalidOrderDirection,
+
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+
+    #[msg("Market vault does not cover its liabilities")]
+    MarketInsolvent,
+
+    #[msg("Voting is not active")]
+    VotingNotActive,
+
+    #[msg("Voting still active")]
+    VotingStillActive,
+
+    #[msg("Not enough votes")]
+    NotEnoughVotes,
+
+    #[msg("Voting market already promoted")]
+    VotingMarketAlreadyPromoted,
+
+    #[msg("Invalid market parameters")]
+    InvalidMarketParameters,
+
+    #[msg("Invalid winning direction")]
+    InvalidWinningDirection,
+
+    #[msg("Bond already settled")]
+    BondAlreadySettled,
+
+    #[msg("User stake limit exceeded")]
+    UserStakeLimitExceeded,
+
+    #[msg("Order size limit exceeded")]
+    OrderSizeLimitExceeded,
+
+    #[msg("Open interest limit exceeded")]
+    OpenInterestLimitExceeded,
+
+    #[msg("Reward epoch not ended")]
+    RewardEpochNotEnded,
+
+    #[msg("Reward already claimed")]
+    RewardAlreadyClaimed,
+
+    #[msg("Invalid asset")]
+    InvalidAsset,
+
+    #[msg("Invalid referral")]
+    InvalidReferral,
+
+    #[msg("Invalid season")]
+    InvalidSeason,
+
+    #[msg("Season not ended")]
+    SeasonNotEnded,
+
+    #[msg("Not ranked")]
+    NotRanked,
+
+    #[msg("Invalid market metadata")]
+    InvalidMarketMetadata,
+
+    #[msg("Market already started")]
+    MarketAlreadyStarted,
+
+    #[msg("Market is not SOL-denominated")]
+    NotNativeMarket,
+
+    #[msg("Invalid order trigger")]
+    InvalidOrderTrigger,
+
+    #[msg("Order trigger not reached")]
+    OrderTriggerNotReached,
+
+    #[msg("Slippage exceeded")]
+    SlippageExceeded,
+
+    #[msg("Invalid market mode")]
+    InvalidMarketMode,
+
+    #[msg("Batch window closed")]
+    BatchClosed,
+
+    #[msg("Batch window not ended")]
+    BatchNotEnded,
+
+    #[msg("Invalid mint")]
+    InvalidMint,
+
+    #[msg("Open interest not recorded")]
+    OpenInterestNotRecorded,
+
+    #[msg("Market history required")]
+    MarketHistoryRequired,
+
+    #[msg("Season required")]
+    SeasonRequired,
+
+    #[msg("Season already joined")]
+    SeasonAlreadyJoined,
+
+    #[msg("Trigger price not settled")]
+    PriceNotSettled,
+
+    #[msg("Batch not cleared")]
+    BatchNotCleared,
+
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
 }
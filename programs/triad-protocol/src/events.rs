@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{ OrderDirection, OrderStatus, OrderType, WinningDirection };
+use crate::state::{ BondStatus, OrderDirection, OrderStatus, OrderType, Rarity, WinningDirection };
 
 #[event]
 pub struct PriceUpdate {
     pub market_id: u64,
+    pub seq: u64,
     pub hype_price: u64,
     pub flop_price: u64,
     pub direction: OrderDirection,
@@ -15,6 +16,7 @@ pub struct PriceUpdate {
 pub struct OrderUpdate {
     pub user: Pubkey,
     pub market_id: u64,
+    pub seq: u64,
     pub question_id: u64,
     pub order_id: u64,
     pub direction: OrderDirection,
@@ -32,6 +34,7 @@ pub struct OrderUpdate {
 #[event]
 pub struct MarketUpdate {
     pub market_id: u64,
+    pub seq: u64,
     pub question: String,
     pub start_time: i64,
     pub end_time: i64,
@@ -43,6 +46,7 @@ pub struct MarketUpdate {
     pub timestamp: i64,
     pub total_hype_shares: u64,
     pub total_flop_shares: u64,
+    pub payout_per_share: u64,
 }
 
 #[event]
@@ -53,3 +57,287 @@ pub struct StakeRewards {
     pub timestamp: i64,
     pub rank: u16,
 }
+
+#[event]
+pub struct SolvencyUpdate {
+    pub market_id: u64,
+    pub vault_balance: u64,
+    pub outstanding_liabilities: u64,
+    pub unclaimed_fees: u64,
+    pub is_solvent: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityUpdate {
+    pub market_id: u64,
+    pub seq: u64,
+    pub user: Pubkey,
+    pub direction: OrderDirection,
+    pub amount: u64,
+    pub hype_liquidity: u64,
+    pub flop_liquidity: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeCollected {
+    pub market_id: u64,
+    pub seq: u64,
+    pub authority: Pubkey,
+    pub market_fee: u64,
+    pub nft_holders_fee: u64,
+    pub creator_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketLimitsUpdate {
+    pub market_id: u64,
+    pub seq: u64,
+    pub max_user_stake: u64,
+    pub max_order_liquidity_bps: u16,
+    pub max_open_interest: u64,
+    pub referral_fee_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketBondUpdate {
+    pub market_id: u64,
+    pub seq: u64,
+    pub creator: Pubkey,
+    pub resolver: Pubkey,
+    pub amount: u64,
+    pub status: BondStatus,
+    pub timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum StakeAction {
+    Stake,
+    RequestWithdraw,
+    Withdraw,
+    Boost,
+}
+
+#[event]
+pub struct StakeUpdate {
+    pub user: Pubkey,
+    pub stake: Pubkey,
+    pub stake_vault: Pubkey,
+    pub mint: Pubkey,
+    pub action: StakeAction,
+    pub amount: u64,
+    pub withdraw_ts: i64,
+    pub boost: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeVaultUpdate {
+    pub stake_vault: Pubkey,
+    pub amount: u64,
+    pub token_staked: u64,
+    pub nft_staked: u64,
+    pub is_locked: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserUpdate {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+    pub referral: Pubkey,
+    pub name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollectionUpdate {
+    pub collection: Pubkey,
+    pub core_collection: Pubkey,
+    pub symbol: String,
+    pub supply: u64,
+    pub minted: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TicketMinted {
+    pub user: Pubkey,
+    pub collection: Pubkey,
+    pub asset: Pubkey,
+    pub number: u64,
+    pub rarity: Rarity,
+    pub is_boosted: bool,
+    pub price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NftRewardEpoch {
+    pub reward_pool: Pubkey,
+    pub epoch: u64,
+    pub reward_per_asset: u64,
+    pub epoch_rewards: u64,
+    pub pending_rewards: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NftRewardsClaimed {
+    pub user: Pubkey,
+    pub asset: Pubkey,
+    pub reward_pool: Pubkey,
+    pub epoch: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralFeeUpdate {
+    pub market_id: u64,
+    pub seq: u64,
+    pub referrer: Pubkey,
+    pub trader: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralRewardsClaimed {
+    pub referrer: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeasonUpdate {
+    pub season_id: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub prize_pool: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeasonPrizeClaimed {
+    pub season_id: u64,
+    pub user: Pubkey,
+    pub rank: u8,
+    pub score: i64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrderTriggersUpdate {
+    pub user: Pubkey,
+    pub market_id: u64,
+    pub seq: u64,
+    pub order_id: u64,
+    pub stop_loss_price: u64,
+    pub take_profit_price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrderTriggered {
+    pub user: Pubkey,
+    pub market_id: u64,
+    pub seq: u64,
+    pub order_id: u64,
+    pub keeper: Pubkey,
+    pub price: u64,
+    pub keeper_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchAuctionUpdate {
+    pub market_id: u64,
+    pub seq: u64,
+    pub batch_id: u64,
+    pub batch_end: i64,
+    pub hype_amount: u64,
+    pub flop_amount: u64,
+    pub pending_orders: u64,
+    pub is_clearing: bool,
+    pub clearing_hype_price: u64,
+    pub clearing_flop_price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VotingMarketUpdate {
+    pub voting_market: Pubkey,
+    pub vote_id: u64,
+    pub authority: Pubkey,
+    pub question: String,
+    pub market_id: u64,
+    pub voting_end: i64,
+    pub market_start: i64,
+    pub market_end: i64,
+    pub total_hype: u64,
+    pub total_flop: u64,
+    pub is_promoted: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteCast {
+    pub voting_market: Pubkey,
+    pub voter: Pubkey,
+    pub direction: OrderDirection,
+    pub votes: u64,
+    pub total_hype: u64,
+    pub total_flop: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketHistoryUpdate {
+    pub market_id: u64,
+    pub seq: u64,
+    pub market_history: Pubkey,
+    pub interval: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketMetadataUpdate {
+    pub market_id: u64,
+    pub seq: u64,
+    pub market_metadata: Pubkey,
+    pub question: String,
+    pub category: String,
+    pub image: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserTradeUpdate {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+    pub user_trade: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeasonJoined {
+    pub season_id: u64,
+    pub user: Pubkey,
+    pub end_ts: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralRewardsUpdate {
+    pub referral_rewards: Pubkey,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{ User, ReferralRewards },
+    errors::TriadProtocolError,
+    events::ReferralRewardsClaimed,
+    constraints::is_authority_for_user,
+    utils::transfer_checked_with_hook,
+};
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(constraint = is_authority_for_user(&user, &signer)?)]
+    pub user: Box<Account<'info, User>>,
+
+    #[account(
+        mut,
+        seeds = [ReferralRewards::PREFIX_SEED, user.key().as_ref(), referral_rewards.mint.as_ref()],
+        bump = referral_rewards.bump
+    )]
+    pub referral_rewards: Box<Account<'info, ReferralRewards>>,
+
+    #[account(
+        constraint = mint.key() == referral_rewards.mint,
+        mint::token_program = token_program
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = referral_rewards,
+        associated_token::token_program = token_program
+    )]
+    pub referral_rewards_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_referral_rewards<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClaimReferralRewards<'info>>
+) -> Result<()> {
+    let referral_rewards = &mut ctx.accounts.referral_rewards;
+
+    let amount = referral_rewards.earned.checked_sub(referral_rewards.claimed).unwrap();
+
+    require!(amount > 0, TriadProtocolError::InsufficientFunds);
+
+    let user_key = ctx.accounts.user.key();
+    let signer: &[&[&[u8]]] = &[
+        &[
+            ReferralRewards::PREFIX_SEED,
+            user_key.as_ref(),
+            referral_rewards.mint.as_ref(),
+            &[referral_rewards.bump],
+        ],
+    ];
+
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.referral_rewards_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.user_ata.to_account_info(),
+                authority: referral_rewards.to_account_info(),
+            },
+            signer
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.mint.decimals
+    )?;
+
+    referral_rewards.claimed = referral_rewards.claimed.checked_add(amount).unwrap();
+
+    emit!(ReferralRewardsClaimed {
+        referrer: user_key,
+        authority: *ctx.accounts.signer.key,
+        amount,
+        total_claimed: referral_rewards.claimed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
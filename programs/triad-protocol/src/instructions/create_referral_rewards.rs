@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{ User, ReferralRewards },
+    events::ReferralRewardsUpdate,
+    constraints::is_authority_for_user,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateReferralRewards<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(constraint = is_authority_for_user(&user, &signer)?)]
+    pub user: Box<Account<'info, User>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = ReferralRewards::SPACE,
+        seeds = [ReferralRewards::PREFIX_SEED, user.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub referral_rewards: Box<Account<'info, ReferralRewards>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = referral_rewards,
+        associated_token::token_program = token_program
+    )]
+    pub referral_rewards_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_referral_rewards(ctx: Context<CreateReferralRewards>) -> Result<()> {
+    ctx.accounts.referral_rewards.set_inner(ReferralRewards {
+        bump: ctx.bumps.referral_rewards,
+        user: ctx.accounts.user.key(),
+        mint: ctx.accounts.mint.key(),
+        earned: 0,
+        claimed: 0,
+        padding: [0; 32],
+    });
+
+    emit_cpi!(ReferralRewardsUpdate {
+        referral_rewards: ctx.accounts.referral_rewards.key(),
+        user: ctx.accounts.user.key(),
+        mint: ctx.accounts.mint.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
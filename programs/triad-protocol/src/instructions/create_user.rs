@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{ state::User, CreateUserArgs };
+use crate::{ state::User, events::UserUpdate, CreateUserArgs };
 
 #[derive(Accounts)]
 #[instruction(args: CreateUserArgs)]
@@ -43,5 +43,13 @@ pub fn create_user(ctx: Context<CreateUser>, args: CreateUserArgs) -> Result<()>
 
     referral.referred += 1;
 
+    emit!(UserUpdate {
+        user: user.key(),
+        authority: user.authority,
+        referral: user.referral,
+        name: user.name.clone(),
+        timestamp: user.ts,
+    });
+
     Ok(())
 }
@@ -1,10 +1,14 @@
 mod create_user;
+mod create_referral_rewards;
+mod claim_referral_rewards;
 mod stake;
 mod trade;
 mod withdraw_v1;
 mod nft;
 
 pub use create_user::*;
+pub use create_referral_rewards::*;
+pub use claim_referral_rewards::*;
 pub use stake::*;
 pub use trade::*;
 pub use withdraw_v1::*;
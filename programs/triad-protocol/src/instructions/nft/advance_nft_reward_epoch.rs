@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ Collection, NftRewardPool },
+    errors::TriadProtocolError,
+    events::NftRewardEpoch,
+};
+
+#[derive(Accounts)]
+pub struct AdvanceNftRewardEpoch<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(mut, has_one = collection)]
+    pub reward_pool: Box<Account<'info, NftRewardPool>>,
+
+    pub collection: Box<Account<'info, Collection>>,
+}
+
+pub fn advance_nft_reward_epoch(ctx: Context<AdvanceNftRewardEpoch>) -> Result<()> {
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let tickets = ctx.accounts.collection.minted;
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    require!(
+        ts >= reward_pool.epoch_start.checked_add(reward_pool.epoch_duration).unwrap(),
+        TriadProtocolError::RewardEpochNotEnded
+    );
+    require!(tickets > 0, TriadProtocolError::InsufficientFunds);
+
+    reward_pool.advance_epoch(tickets, ts);
+
+    emit!(NftRewardEpoch {
+        reward_pool: reward_pool.key(),
+        epoch: reward_pool.epoch,
+        reward_per_asset: reward_pool.reward_per_asset,
+        epoch_rewards: reward_pool.epoch_rewards,
+        pending_rewards: reward_pool.pending_rewards,
+        timestamp: ts,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+use mpl_core::{ accounts::BaseAssetV1, types::UpdateAuthority };
+
+use crate::{
+    state::{ NftRewardPool, NftRewardClaim },
+    errors::TriadProtocolError,
+    events::NftRewardsClaimed,
+    utils::transfer_checked_with_hook,
+};
+
+#[derive(Accounts)]
+pub struct ClaimNftRewards<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub reward_pool: Box<Account<'info, NftRewardPool>>,
+
+    #[account(
+        constraint = asset.owner == signer.key() @ TriadProtocolError::InvalidAsset,
+        constraint = asset.update_authority ==
+        UpdateAuthority::Collection(reward_pool.core_collection) @ TriadProtocolError::InvalidAsset
+    )]
+    pub asset: Box<Account<'info, BaseAssetV1>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = NftRewardClaim::SPACE,
+        seeds = [NftRewardClaim::PREFIX_SEED, reward_pool.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    pub reward_claim: Box<Account<'info, NftRewardClaim>>,
+
+    #[account(constraint = mint.key() == reward_pool.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = reward_pool,
+        associated_token::token_program = token_program
+    )]
+    pub reward_pool_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_nft_rewards<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClaimNftRewards<'info>>
+) -> Result<()> {
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let reward_claim = &mut ctx.accounts.reward_claim;
+
+    require!(reward_claim.epoch < reward_pool.epoch, TriadProtocolError::RewardAlreadyClaimed);
+
+    let amount = reward_pool.reward_per_asset;
+    let epoch_claimed = reward_pool.epoch_claimed.checked_add(amount).unwrap();
+
+    require!(
+        amount > 0 && epoch_claimed <= reward_pool.epoch_rewards,
+        TriadProtocolError::InsufficientFunds
+    );
+
+    let signer: &[&[&[u8]]] = &[
+        &[NftRewardPool::PREFIX_SEED, reward_pool.collection.as_ref(), &[reward_pool.bump]],
+    ];
+
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.reward_pool_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.user_ata.to_account_info(),
+                authority: reward_pool.to_account_info(),
+            },
+            signer
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.mint.decimals
+    )?;
+
+    reward_pool.epoch_claimed = epoch_claimed;
+    reward_pool.total_claimed = reward_pool.total_claimed.checked_add(amount).unwrap();
+
+    reward_claim.bump = ctx.bumps.reward_claim;
+    reward_claim.reward_pool = reward_pool.key();
+    reward_claim.asset = ctx.accounts.asset.key();
+    reward_claim.epoch = reward_pool.epoch;
+    reward_claim.total_claimed = reward_claim.total_claimed.checked_add(amount).unwrap();
+
+    emit!(NftRewardsClaimed {
+        user: *ctx.accounts.signer.key,
+        asset: ctx.accounts.asset.key(),
+        reward_pool: reward_pool.key(),
+        epoch: reward_pool.epoch,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
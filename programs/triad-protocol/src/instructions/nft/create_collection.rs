@@ -7,7 +7,7 @@ use mpl_core::{
     types::{ PluginAuthorityPair, Creator },
 };
 
-use crate::{ state::Collection, CreateCollectionArgs };
+use crate::{ state::Collection, events::CollectionUpdate, CreateCollectionArgs };
 
 #[derive(Accounts)]
 #[instruction(args: CreateCollectionArgs)]
@@ -79,5 +79,14 @@ pub fn create_collection(ctx: Context<CreateCollection>, args: CreateCollectionA
         )
         .invoke_signed(seeds)?;
 
+    emit!(CollectionUpdate {
+        collection: collection.key(),
+        core_collection: ctx.accounts.core_collection.key(),
+        symbol: collection.symbol.clone(),
+        supply: collection.supply,
+        minted: collection.minted,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{
+    state::{ Collection, NftRewardPool, CreateNftRewardPoolArgs },
+    errors::TriadProtocolError,
+    events::NftRewardEpoch,
+    constraints::is_admin,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateNftRewardPool<'info> {
+    #[account(mut, constraint = is_admin(&signer)?)]
+    pub signer: Signer<'info>,
+
+    pub collection: Box<Account<'info, Collection>>,
+
+    #[account(constraint = core_collection.update_authority == collection.key())]
+    pub core_collection: Box<Account<'info, BaseCollectionV1>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = NftRewardPool::SPACE,
+        seeds = [NftRewardPool::PREFIX_SEED, collection.key().as_ref()],
+        bump
+    )]
+    pub reward_pool: Box<Account<'info, NftRewardPool>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = reward_pool,
+        associated_token::token_program = token_program
+    )]
+    pub reward_pool_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_nft_reward_pool(
+    ctx: Context<CreateNftRewardPool>,
+    args: CreateNftRewardPoolArgs
+) -> Result<()> {
+    require!(args.epoch_duration > 0, TriadProtocolError::InvalidMarketParameters);
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.reward_pool.set_inner(NftRewardPool {
+        bump: ctx.bumps.reward_pool,
+        collection: ctx.accounts.collection.key(),
+        core_collection: ctx.accounts.core_collection.key(),
+        mint: ctx.accounts.mint.key(),
+        epoch: 0,
+        epoch_start: ts,
+        epoch_duration: args.epoch_duration,
+        pending_rewards: 0,
+        reward_per_asset: 0,
+        epoch_rewards: 0,
+        epoch_claimed: 0,
+        total_claimed: 0,
+        padding: [0; 64],
+    });
+
+    let reward_pool = &ctx.accounts.reward_pool;
+
+    emit_cpi!(NftRewardEpoch {
+        reward_pool: reward_pool.key(),
+        epoch: reward_pool.epoch,
+        reward_per_asset: reward_pool.reward_per_asset,
+        epoch_rewards: reward_pool.epoch_rewards,
+        pending_rewards: reward_pool.pending_rewards,
+        timestamp: ts,
+    });
+
+    Ok(())
+}
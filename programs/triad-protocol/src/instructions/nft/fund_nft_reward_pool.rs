@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{ MarketV2, NftRewardPool },
+    errors::TriadProtocolError,
+    events::FeeCollected,
+    utils::transfer_checked_with_hook,
+};
+
+/// Permissionless, moves the NFT holders' fee share of a market into the reward pool
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundNftRewardPool<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(mut, constraint = reward_pool.mint == market.mint)]
+    pub reward_pool: Box<Account<'info, NftRewardPool>>,
+
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program
+    )]
+    pub market_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = reward_pool,
+        associated_token::token_program = token_program
+    )]
+    pub reward_pool_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn fund_nft_reward_pool<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, FundNftRewardPool<'info>>
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let reward_pool = &mut ctx.accounts.reward_pool;
+
+    let amount = market.nft_holders_fee_available
+        .checked_sub(market.nft_holders_fee_claimed)
+        .unwrap();
+
+    require!(amount > 0, TriadProtocolError::InsufficientFunds);
+
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.market_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.reward_pool_ata.to_account_info(),
+                authority: market.to_account_info(),
+            },
+            &[&[b"market", &market.market_id.to_le_bytes(), &[market.bump]]]
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.mint.decimals
+    )?;
+
+    market.nft_holders_fee_claimed = market.nft_holders_fee_claimed.checked_add(amount).unwrap();
+    reward_pool.pending_rewards = reward_pool.pending_rewards.checked_add(amount).unwrap();
+
+    emit_cpi!(FeeCollected {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        authority: reward_pool.key(),
+        market_fee: 0,
+        nft_holders_fee: amount,
+        creator_fee: 0,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    ctx.accounts.market_ata.reload()?;
+    market.check_solvency(ctx.accounts.market_ata.amount)?;
+
+    Ok(())
+}
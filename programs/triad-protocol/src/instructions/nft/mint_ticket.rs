@@ -15,7 +15,8 @@ use anchor_spl::token_2022::{ burn, close_account, Burn, CloseAccount };
 
 use crate::{
     errors::TriadProtocolError,
-    state::{ Collection, Rarity, Nft },
+    events::TicketMinted,
+    state::{ Collection, Rarity, Nft, NftRewardPool, NftRewardClaim },
     MintTicketArgs,
     constraints::is_verifier,
 };
@@ -53,6 +54,19 @@ pub struct MintTicket<'info> {
     #[account(mut, constraint = core_collection.update_authority == collection.key())]
     pub core_collection: Account<'info, BaseCollectionV1>,
 
+    /// CHECK: reward pool of the collection, read only when it has been created
+    #[account(seeds = [NftRewardPool::PREFIX_SEED, collection.key().as_ref()], bump)]
+    pub reward_pool: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = NftRewardClaim::SPACE,
+        seeds = [NftRewardClaim::PREFIX_SEED, reward_pool.key().as_ref(), asset.key().as_ref()],
+        bump
+    )]
+    pub reward_claim: Box<Account<'info, NftRewardClaim>>,
+
     #[account(mut)]
     pub trd_mint: Box<InterfaceAccount<'info, Mint>>,
 
@@ -119,12 +133,14 @@ pub fn mint_ticket(ctx: Context<MintTicket>, args: MintTicketArgs) -> Result<()>
 
     // Burn the TRD
     let mut price = match args.rarity {
-        Rarity::Common => 150_000_000,
-        Rarity::Uncommon => 100_000_000,
-        Rarity::Rare => 50_000_000,
+        Rarity::Common => 150,
+        Rarity::Uncommon => 100,
+        Rarity::Rare => 50,
         _ => 0u64,
     };
 
+    price = price.checked_mul(10u64.pow(ctx.accounts.trd_mint.decimals as u32)).unwrap();
+
     let discount = price.checked_mul(args.discount).unwrap().checked_div(100).unwrap();
     price = price.checked_sub(discount).unwrap();
 
@@ -202,5 +218,33 @@ pub fn mint_ticket(ctx: Context<MintTicket>, args: MintTicketArgs) -> Result<()>
 
     collection.minted = collection.minted.checked_add(1).unwrap();
 
+    // The ticket is counted from the next epoch closed, not the ones already closed
+    let reward_pool_info = ctx.accounts.reward_pool.to_account_info();
+    let epoch = if reward_pool_info.owner == &crate::ID {
+        NftRewardPool::try_deserialize(&mut &reward_pool_info.try_borrow_data()?[..])?.epoch
+    } else {
+        0
+    };
+
+    ctx.accounts.reward_claim.set_inner(NftRewardClaim {
+        bump: ctx.bumps.reward_claim,
+        reward_pool: ctx.accounts.reward_pool.key(),
+        asset: ctx.accounts.asset.key(),
+        epoch,
+        total_claimed: 0,
+        padding: [0; 32],
+    });
+
+    emit!(TicketMinted {
+        user: *ctx.accounts.signer.key,
+        collection: collection.key(),
+        asset: ctx.accounts.asset.key(),
+        number,
+        rarity: args.rarity,
+        is_boosted: args.is_boosted,
+        price,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
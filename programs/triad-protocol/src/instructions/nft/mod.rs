@@ -1,5 +1,13 @@
 mod create_collection;
 mod mint_ticket;
+mod create_nft_reward_pool;
+mod fund_nft_reward_pool;
+mod advance_nft_reward_epoch;
+mod claim_nft_rewards;
 
 pub use create_collection::*;
 pub use mint_ticket::*;
+pub use create_nft_reward_pool::*;
+pub use fund_nft_reward_pool::*;
+pub use advance_nft_reward_epoch::*;
+pub use claim_nft_rewards::*;
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{ transfer_checked, Token2022, TransferChecked };
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
 use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
 
 use crate::{
@@ -7,8 +7,10 @@ use crate::{
     events::StakeRewards,
     constraints::{ is_authority_for_stake, is_mint_for_stake_vault, is_verifier },
     errors::TriadProtocolError,
+    utils::transfer_checked_with_hook,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: ClaimStakeRewardsArgs)]
 pub struct ClaimStakeRewards<'info> {
@@ -24,7 +26,11 @@ pub struct ClaimStakeRewards<'info> {
     #[account(mut, constraint = is_authority_for_stake(&stake, &signer)?)]
     pub stake: Box<Account<'info, StakeV2>>,
 
-    #[account(mut, constraint = is_mint_for_stake_vault(&stake_vault, &mint.key())?)]
+    #[account(
+        mut,
+        constraint = is_mint_for_stake_vault(&stake_vault, &mint.key())?,
+        mint::token_program = token_program
+    )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -44,13 +50,13 @@ pub struct ClaimStakeRewards<'info> {
     )]
     pub to_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn claim_stake_rewards(
-    ctx: Context<ClaimStakeRewards>,
+pub fn claim_stake_rewards<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClaimStakeRewards<'info>>,
     args: ClaimStakeRewardsArgs
 ) -> Result<u64> {
     let stake_vault: &mut Box<Account<StakeVault>> = &mut ctx.accounts.stake_vault;
@@ -80,7 +86,7 @@ pub fn claim_stake_rewards(
         rank = 963;
     }
 
-    let max_rank = 1633 as f64;
+    let max_rank = 1633.0;
     let rank = rank as f64;
     let rank_weight = (max_rank - rank + 1.0) / max_rank;
 
@@ -116,7 +122,7 @@ pub fn claim_stake_rewards(
 
     let checked_rewards = (rewards as u64) + stake.available;
 
-    transfer_checked(
+    transfer_checked_with_hook(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             TransferChecked {
@@ -126,7 +132,7 @@ pub fn claim_stake_rewards(
                 authority: stake_vault.to_account_info(),
             },
             signer
-        ),
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         checked_rewards,
         ctx.accounts.mint.decimals
     )?;
@@ -143,7 +149,7 @@ pub fn claim_stake_rewards(
         return Err(TriadProtocolError::StakeVaultLocked.into());
     }
 
-    emit!(StakeRewards {
+    emit_cpi!(StakeRewards {
         user: ctx.accounts.signer.key(),
         mint: stake.mint,
         amount: checked_rewards,
@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::TokenInterface;
 use anchor_spl::token_interface::Mint;
 
 use crate::{
     errors::TriadProtocolError,
+    events::{ StakeUpdate, StakeAction },
     state::{ StakeV2, User, StakeVault },
     constraints::{ is_authority_for_stake, is_mint_for_stake },
 };
@@ -22,10 +23,14 @@ pub struct RequestWithdrawStake<'info> {
     #[account(mut, constraint = is_authority_for_stake(&stake, &signer)?)]
     pub stake: Box<Account<'info, StakeV2>>,
 
-    #[account(mut, constraint = is_mint_for_stake(&stake, &mint.key())?)]
+    #[account(
+        mut,
+        constraint = is_mint_for_stake(&stake, &mint.key())?,
+        mint::token_program = token_program
+    )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -46,5 +51,17 @@ pub fn request_withdraw_stake(ctx: Context<RequestWithdrawStake>) -> Result<()>
 
     stake.withdraw_ts = Clock::get()?.unix_timestamp + days * 24 * 60 * 60;
 
+    emit!(StakeUpdate {
+        user: stake.authority,
+        stake: stake.key(),
+        stake_vault: stake.stake_vault,
+        mint: stake.mint,
+        action: StakeAction::RequestWithdraw,
+        amount: stake.amount,
+        withdraw_ts: stake.withdraw_ts,
+        boost: stake.boost,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
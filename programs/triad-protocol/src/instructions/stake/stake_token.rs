@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{ Token2022, transfer_checked, TransferChecked };
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
 use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
 
 use crate::{
     constraints::is_mint_for_stake_vault,
     errors::TriadProtocolError,
+    events::{ StakeUpdate, StakeAction },
     state::{ StakeTokenArgs, StakeVault, StakeV2, User },
+    utils::{ get_transfer_fee, transfer_checked_with_hook },
 };
 
 #[derive(Accounts)]
@@ -29,7 +31,11 @@ pub struct StakeToken<'info> {
     )]
     pub stake: Box<Account<'info, StakeV2>>,
 
-    #[account(mut, constraint = is_mint_for_stake_vault(&stake_vault, &mint.key())?)]
+    #[account(
+        mut,
+        constraint = is_mint_for_stake_vault(&stake_vault, &mint.key())?,
+        mint::token_program = token_program
+    )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -50,12 +56,15 @@ pub struct StakeToken<'info> {
     )]
     pub to_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn stake_token(ctx: Context<StakeToken>, args: StakeTokenArgs) -> Result<()> {
+pub fn stake_token<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, StakeToken<'info>>,
+    args: StakeTokenArgs
+) -> Result<()> {
     let mint = &ctx.accounts.mint.to_account_info();
     let stake = &mut ctx.accounts.stake;
     let stake_vault = &mut ctx.accounts.stake_vault;
@@ -76,22 +85,38 @@ pub fn stake_token(ctx: Context<StakeToken>, args: StakeTokenArgs) -> Result<()>
     stake.stake_vault = stake_vault.key();
     stake.claimed = 0;
     stake.available = 0;
-    stake.amount = args.amount;
 
-    stake_vault.token_staked = stake_vault.token_staked.checked_add(args.amount).unwrap();
+    // Only what lands in the vault after Token-2022 transfer fees counts as staked
+    let amount = args.amount.checked_sub(get_transfer_fee(mint, args.amount)?).unwrap();
+
+    stake.amount = amount;
 
-    user.staked = user.staked.checked_add(args.amount).unwrap();
+    stake_vault.token_staked = stake_vault.token_staked.checked_add(amount).unwrap();
 
-    transfer_checked(
+    user.staked = user.staked.checked_add(amount).unwrap();
+
+    transfer_checked_with_hook(
         CpiContext::new(ctx.accounts.token_program.to_account_info(), TransferChecked {
             from: ctx.accounts.from_ata.to_account_info(),
             mint: ctx.accounts.mint.to_account_info(),
             to: ctx.accounts.to_ata.to_account_info(),
             authority: ctx.accounts.signer.to_account_info(),
-        }),
+        }).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         args.amount,
         ctx.accounts.mint.decimals
     )?;
 
+    emit!(StakeUpdate {
+        user: stake.authority,
+        stake: stake.key(),
+        stake_vault: stake.stake_vault,
+        mint: stake.mint,
+        action: StakeAction::Stake,
+        amount: stake.amount,
+        withdraw_ts: stake.withdraw_ts,
+        boost: stake.boost,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
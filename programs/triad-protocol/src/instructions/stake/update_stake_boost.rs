@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{ constraints::is_admin, state::StakeV2 };
+use crate::{ constraints::is_admin, state::StakeV2, events::{ StakeUpdate, StakeAction } };
 
 #[derive(Accounts)]
 pub struct UpdateStakeBoost<'info> {
@@ -18,5 +18,17 @@ pub fn update_stake_boost(ctx: Context<UpdateStakeBoost>) -> Result<()> {
 
     stake.boost = true;
 
+    emit!(StakeUpdate {
+        user: stake.authority,
+        stake: stake.key(),
+        stake_vault: stake.stake_vault,
+        mint: stake.mint,
+        action: StakeAction::Boost,
+        amount: stake.amount,
+        withdraw_ts: stake.withdraw_ts,
+        boost: stake.boost,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
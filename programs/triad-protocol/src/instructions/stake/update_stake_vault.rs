@@ -1,13 +1,15 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::TokenInterface;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{ transfer_checked, Mint, TokenAccount, TransferChecked },
+    token_interface::{ Mint, TokenAccount, TransferChecked },
 };
 
 use crate::{
     constraints::is_authority_for_stake_vault,
     state::{ UpdateStakeVaultArgs, StakeVault },
+    events::StakeVaultUpdate,
+    utils::transfer_checked_with_hook,
 };
 
 #[derive(Accounts)]
@@ -24,7 +26,7 @@ pub struct UpdateStakeVault<'info> {
     )]
     pub stake_vault: Box<Account<'info, StakeVault>>,
 
-    #[account(mut)]
+    #[account(mut, mint::token_program = token_program)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -43,35 +45,44 @@ pub struct UpdateStakeVault<'info> {
     )]
     pub to_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn update_stake_vault(
-    ctx: Context<UpdateStakeVault>,
+pub fn update_stake_vault<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateStakeVault<'info>>,
     args: UpdateStakeVaultArgs
 ) -> Result<()> {
     let stake_vault = &mut ctx.accounts.stake_vault;
 
-    if args.amount.is_some() {
-        transfer_checked(
+    if let Some(amount) = args.amount {
+        transfer_checked_with_hook(
             CpiContext::new(ctx.accounts.token_program.to_account_info(), TransferChecked {
                 from: ctx.accounts.from_ata.to_account_info(),
                 mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.to_ata.to_account_info(),
                 authority: ctx.accounts.signer.to_account_info(),
-            }),
-            args.amount.unwrap(),
+            }).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            amount,
             ctx.accounts.mint.decimals
         )?;
 
-        stake_vault.amount = stake_vault.amount.checked_add(args.amount.unwrap()).unwrap();
+        stake_vault.amount = stake_vault.amount.checked_add(amount).unwrap();
     }
 
-    if args.is_locked.is_some() {
-        stake_vault.is_locked = args.is_locked.unwrap();
+    if let Some(is_locked) = args.is_locked {
+        stake_vault.is_locked = is_locked;
     }
 
+    emit!(StakeVaultUpdate {
+        stake_vault: stake_vault.key(),
+        amount: stake_vault.amount,
+        token_staked: stake_vault.token_staked,
+        nft_staked: stake_vault.nft_staked,
+        is_locked: stake_vault.is_locked,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{ close_account, CloseAccount, Token2022 };
+use anchor_spl::token_interface::{ close_account, CloseAccount, TokenInterface };
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{ transfer_checked, Mint, TokenAccount, TransferChecked },
+    token_interface::{ Mint, TokenAccount, TransferChecked },
 };
 
 use crate::{
@@ -10,7 +10,9 @@ use crate::{
     constants::ADMIN,
     constraints::{ is_authority_for_stake, is_mint_for_stake },
     errors::TriadProtocolError,
+    events::{ StakeUpdate, StakeAction },
     StakeVault,
+    utils::transfer_checked_with_hook,
 };
 
 #[derive(Accounts)]
@@ -31,7 +33,11 @@ pub struct WithdrawStake<'info> {
     #[account(mut, constraint = admin.key.to_string() == ADMIN)]
     pub admin: AccountInfo<'info>,
 
-    #[account(mut, constraint = is_mint_for_stake(&stake, &mint.key())?)]
+    #[account(
+        mut,
+        constraint = is_mint_for_stake(&stake, &mint.key())?,
+        mint::token_program = token_program
+    )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -51,12 +57,14 @@ pub struct WithdrawStake<'info> {
     )]
     pub to_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+pub fn withdraw_stake<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, WithdrawStake<'info>>
+) -> Result<()> {
     let stake = &mut ctx.accounts.stake;
     let stake_vault = &mut ctx.accounts.stake_vault;
 
@@ -76,7 +84,7 @@ pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
         amount = stake.amount;
     }
 
-    transfer_checked(
+    transfer_checked_with_hook(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             TransferChecked {
@@ -86,7 +94,7 @@ pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
                 authority: stake_vault.to_account_info(),
             },
             signer
-        ),
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         amount,
         ctx.accounts.mint.decimals
     )?;
@@ -109,5 +117,17 @@ pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
         stake_vault.nft_staked = stake_vault.nft_staked.checked_sub(1).unwrap();
     }
 
+    emit!(StakeUpdate {
+        user: stake.authority,
+        stake: stake.key(),
+        stake_vault: stake.stake_vault,
+        mint: stake.mint,
+        action: StakeAction::Withdraw,
+        amount,
+        withdraw_ts: stake.withdraw_ts,
+        boost: stake.boost,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -1,9 +1,15 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{ Token2022, transfer_checked, TransferChecked };
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
 use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
 
-use crate::{ state::{ MarketV2, OrderDirection, AddLiquidityArgs }, errors::TriadProtocolError };
+use crate::{
+    state::{ MarketV2, OrderDirection, AddLiquidityArgs },
+    errors::TriadProtocolError,
+    events::LiquidityUpdate,
+    utils::{ get_transfer_fee, transfer_checked_with_hook },
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: AddLiquidityArgs)]
 pub struct AddLiquidity<'info> {
@@ -13,7 +19,7 @@ pub struct AddLiquidity<'info> {
     #[account(mut, constraint = market.authority == signer.key())]
     pub market: Box<Account<'info, MarketV2>>,
 
-    #[account(mut, constraint = mint.key() == market.mint)]
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -34,35 +40,52 @@ pub struct AddLiquidity<'info> {
     )]
     pub market_to_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn add_liquidity(ctx: Context<AddLiquidity>, args: AddLiquidityArgs) -> Result<()> {
+pub fn add_liquidity<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, AddLiquidity<'info>>,
+    args: AddLiquidityArgs
+) -> Result<()> {
     let market = &mut ctx.accounts.market;
 
     require!(!market.is_active, TriadProtocolError::MarketInactive);
 
+    let transfer_fee = get_transfer_fee(&ctx.accounts.mint.to_account_info(), args.amount)?;
+    let amount = args.amount.checked_sub(transfer_fee).unwrap();
+
     match args.direction {
         OrderDirection::Hype => {
-            market.hype_liquidity = market.hype_liquidity.checked_add(args.amount).unwrap();
+            market.hype_liquidity = market.hype_liquidity.checked_add(amount).unwrap();
         }
         OrderDirection::Flop => {
-            market.flop_liquidity = market.flop_liquidity.checked_add(args.amount).unwrap();
+            market.flop_liquidity = market.flop_liquidity.checked_add(amount).unwrap();
         }
     }
 
-    transfer_checked(
+    transfer_checked_with_hook(
         CpiContext::new(ctx.accounts.token_program.to_account_info(), TransferChecked {
             from: ctx.accounts.user_from_ata.to_account_info(),
             mint: ctx.accounts.mint.to_account_info(),
             to: ctx.accounts.market_to_ata.to_account_info(),
             authority: ctx.accounts.signer.to_account_info(),
-        }),
+        }).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         args.amount,
         ctx.accounts.mint.decimals
     )?;
 
+    emit_cpi!(LiquidityUpdate {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        user: *ctx.accounts.signer.key,
+        direction: args.direction,
+        amount,
+        hype_liquidity: market.hype_liquidity,
+        flop_liquidity: market.flop_liquidity,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::Season,
+    errors::TriadProtocolError,
+    events::SeasonPrizeClaimed,
+    utils::transfer_checked_with_hook,
+};
+
+#[derive(Accounts)]
+pub struct ClaimSeasonPrize<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub season: Box<Account<'info, Season>>,
+
+    #[account(constraint = mint.key() == season.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = season,
+        associated_token::token_program = token_program
+    )]
+    pub season_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_season_prize<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClaimSeasonPrize<'info>>
+) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    require!(ts >= season.end_ts, TriadProtocolError::SeasonNotEnded);
+
+    let rank = season.rank(ctx.accounts.signer.key).ok_or(TriadProtocolError::NotRanked)?;
+
+    require!(
+        season.claimed_ranks & (1 << rank) == 0,
+        TriadProtocolError::RewardAlreadyClaimed
+    );
+
+    let amount = season.prize(rank);
+
+    require!(amount > 0, TriadProtocolError::NotRanked);
+
+    let signer: &[&[&[u8]]] = &[
+        &[Season::PREFIX_SEED, &season.season_id.to_le_bytes(), &[season.bump]],
+    ];
+
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.season_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.user_ata.to_account_info(),
+                authority: season.to_account_info(),
+            },
+            signer
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.mint.decimals
+    )?;
+
+    season.claimed_ranks |= 1 << rank;
+
+    emit!(SeasonPrizeClaimed {
+        season_id: season.season_id,
+        user: *ctx.accounts.signer.key,
+        rank: rank as u8,
+        score: season.leaderboard[rank].score,
+        amount,
+        timestamp: ts,
+    });
+
+    Ok(())
+}
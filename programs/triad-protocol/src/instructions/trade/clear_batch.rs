@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
+
+use crate::{
+    state::{
+        MarketV2,
+        MarketMode,
+        MarketHistory,
+        BatchAuction,
+        UserTrade,
+        OrderDirection,
+        OrderStatus,
+        WinningDirection,
+    },
+    errors::TriadProtocolError,
+    events::{ OrderUpdate, BatchAuctionUpdate },
+};
+
+/// Permissionless crank clearing the ended batch of a `MarketMode::BatchAuction` market.
+///
+/// The first call fixes the clearing prices, every call then fills the `Pending` orders of
+/// the batch found in `remaining_accounts`, a list of writable `user_trade` accounts. Large
+/// batches are filled over several calls, the next batch opens once the last order is filled.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClearBatch<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(mut, has_one = market)]
+    pub batch_auction: Box<Account<'info, BatchAuction>>,
+
+    /// Candle history, required once the market has one
+    #[account(mut, has_one = market)]
+    pub market_history: Option<AccountLoader<'info, MarketHistory>>,
+
+    #[account(constraint = mint.key() == market.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn clear_batch<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClearBatch<'info>>
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let batch_auction = &mut ctx.accounts.batch_auction;
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    require!(market.mode == MarketMode::BatchAuction, TriadProtocolError::InvalidMarketMode);
+    require!(
+        market.winning_direction == WinningDirection::None,
+        TriadProtocolError::MarketAlreadyResolved
+    );
+
+    if !batch_auction.is_clearing {
+        require!(
+            ts >= batch_auction.batch_end && batch_auction.pending_orders > 0,
+            TriadProtocolError::BatchNotEnded
+        );
+
+        let price_update = batch_auction.start_clearing(market, ts);
+
+        emit_cpi!(price_update);
+
+        require!(
+            !market.has_history || ctx.accounts.market_history.is_some(),
+            TriadProtocolError::MarketHistoryRequired
+        );
+
+        if let Some(market_history) = &ctx.accounts.market_history {
+            let volume = batch_auction.hype_amount.checked_add(batch_auction.flop_amount).unwrap();
+
+            market_history.load_mut()?.record_trade(ts, market.hype_price, volume);
+        }
+    }
+
+    for account in ctx.remaining_accounts {
+        let mut user_trade = Account::<UserTrade>::try_from(account)?;
+
+        for order_index in 0..user_trade.orders.len() {
+            let order = user_trade.orders[order_index];
+
+            if
+                order.status != OrderStatus::Pending ||
+                order.market_id != market.market_id ||
+                order.batch_id != batch_auction.batch_id
+            {
+                continue;
+            }
+
+            let price = batch_auction.clearing_price(order.direction);
+            let total_shares = order.total_amount
+                .checked_mul(1_000_000)
+                .unwrap()
+                .checked_div(price)
+                .unwrap();
+
+            match order.direction {
+                OrderDirection::Hype => {
+                    market.hype_shares = market.hype_shares.checked_add(total_shares).unwrap();
+                }
+                OrderDirection::Flop => {
+                    market.flop_shares = market.flop_shares.checked_add(total_shares).unwrap();
+                }
+            }
+
+            batch_auction.fill_order();
+
+            let filled = &mut user_trade.orders[order_index];
+
+            filled.status = OrderStatus::Open;
+            filled.price = price;
+            filled.total_shares = total_shares;
+
+            emit_cpi!(OrderUpdate {
+                user: user_trade.authority,
+                market_id: market.market_id,
+                seq: market.next_event_seq(),
+                order_id: order.order_id,
+                direction: order.direction,
+                order_type: order.order_type,
+                question_id: order.question_id,
+                order_status: OrderStatus::Open,
+                price,
+                total_shares,
+                total_amount: order.total_amount,
+                refund_amount: None,
+                timestamp: ts,
+                is_question_winner: None,
+                pnl: 0,
+            });
+        }
+
+        user_trade.exit(&crate::ID)?;
+    }
+
+    emit_cpi!(BatchAuctionUpdate {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        batch_id: batch_auction.batch_id,
+        batch_end: batch_auction.batch_end,
+        hype_amount: batch_auction.hype_amount,
+        flop_amount: batch_auction.flop_amount,
+        pending_orders: batch_auction.pending_orders,
+        is_clearing: batch_auction.is_clearing,
+        clearing_hype_price: batch_auction.clearing_hype_price,
+        clearing_flop_price: batch_auction.clearing_flop_price,
+        timestamp: ts,
+    });
+
+    market.check_solvency(ctx.accounts.market_vault.amount)?;
+
+    Ok(())
+}
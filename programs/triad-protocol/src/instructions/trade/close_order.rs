@@ -1,14 +1,26 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{ Token2022, transfer_checked, TransferChecked };
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
 use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
 
 use crate::{
-    state::{ MarketV2, UserTrade, OrderStatus, OrderDirection, Order },
+    state::{
+        MarketV2,
+        MarketMode,
+        MarketHistory,
+        UserStats,
+        Season,
+        SeasonScore,
+        UserTrade,
+        OrderStatus,
+        Order,
+    },
     errors::TriadProtocolError,
     events::OrderUpdate,
     constraints::is_authority_for_user_trade,
+    utils::transfer_checked_with_hook,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CloseOrder<'info> {
     #[account(mut)]
@@ -20,10 +32,30 @@ pub struct CloseOrder<'info> {
     )]
     pub user_trade: Box<Account<'info, UserTrade>>,
 
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = UserStats::SPACE,
+        seeds = [UserStats::PREFIX_SEED, signer.key().as_ref(), market.mint.as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
     #[account(mut)]
     pub market: Box<Account<'info, MarketV2>>,
 
-    #[account(mut, constraint = mint.key() == market.mint)]
+    /// Candle history, required once the market has one
+    #[account(mut, has_one = market)]
+    pub market_history: Option<AccountLoader<'info, MarketHistory>>,
+
+    /// Season joined by the signer, required while it runs, with their score
+    #[account(mut)]
+    pub season: Option<Box<Account<'info, Season>>>,
+
+    #[account(mut, constraint = season_score.authority == signer.key())]
+    pub season_score: Option<Box<Account<'info, SeasonScore>>>,
+
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -43,18 +75,23 @@ pub struct CloseOrder<'info> {
     )]
     pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn close_order(ctx: Context<CloseOrder>, order_id: u64) -> Result<()> {
+pub fn close_order<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CloseOrder<'info>>,
+    order_id: u64,
+    min_price: u64
+) -> Result<()> {
     let market = &mut ctx.accounts.market;
     let user_trade = &mut ctx.accounts.user_trade;
 
     let ts = Clock::get()?.unix_timestamp;
 
     require!(market.is_active, TriadProtocolError::MarketInactive);
+    require!(market.mode == MarketMode::Amm, TriadProtocolError::InvalidMarketMode);
     require!(ts > market.market_start, TriadProtocolError::QuestionPeriodNotStarted);
     require!(market.market_end > ts, TriadProtocolError::QuestionPeriodEnded);
 
@@ -65,40 +102,24 @@ pub fn close_order(ctx: Context<CloseOrder>, order_id: u64) -> Result<()> {
 
     let order = user_trade.orders[order_index];
 
-    require!(ts > market.update_ts, TriadProtocolError::ConcurrentTransaction);
     require!(market.market_id == order.market_id, TriadProtocolError::Unauthorized);
 
-    let (current_price, current_liquidity, otherside_current_liquidity) = match order.direction {
-        OrderDirection::Hype => (market.hype_price, market.hype_liquidity, market.flop_liquidity),
-        OrderDirection::Flop => (market.flop_price, market.flop_liquidity, market.hype_liquidity),
-    };
-
-    require!(current_liquidity > 0, TriadProtocolError::InsufficientLiquidity);
-
-    let current_amount = order.total_shares
-        .checked_mul(current_price)
-        .unwrap()
-        .checked_div(1_000_000)
-        .unwrap();
+    let current_price = market.price(order.direction);
 
-    let new_directional_liquidity = current_liquidity.checked_sub(current_amount).unwrap();
-    let markets_liquidity = new_directional_liquidity
-        .checked_add(otherside_current_liquidity)
-        .unwrap();
+    // Earlier trades in the same slot may have moved the price, bound the one we close at
+    require!(min_price > 0, TriadProtocolError::InvalidPrice);
+    require!(current_price >= min_price, TriadProtocolError::SlippageExceeded);
 
-    let new_price = new_directional_liquidity
-        .checked_mul(1_000_000)
-        .unwrap()
-        .checked_div(markets_liquidity)
-        .unwrap()
-        .clamp(1, 999_999);
+    let (current_amount, price_update) = market.close_position(&order, ts)?;
 
-    require!(current_liquidity > current_amount, TriadProtocolError::InsufficientLiquidity);
+    if let Some(price_update) = price_update {
+        emit_cpi!(price_update);
+    }
 
     if current_amount > 0 {
         let signer: &[&[&[u8]]] = &[&[b"market", &market.market_id.to_le_bytes(), &[market.bump]]];
 
-        transfer_checked(
+        transfer_checked_with_hook(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 TransferChecked {
@@ -108,36 +129,47 @@ pub fn close_order(ctx: Context<CloseOrder>, order_id: u64) -> Result<()> {
                     authority: market.to_account_info(),
                 },
                 signer
-            ),
+            ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
             current_amount,
             ctx.accounts.mint.decimals
         )?;
-
-        market.update_price(current_amount, new_price, order.direction, false)?;
-    }
-
-    match order.direction {
-        OrderDirection::Hype => {
-            market.hype_shares = market.hype_shares.checked_sub(order.total_shares).unwrap();
-        }
-        OrderDirection::Flop => {
-            market.flop_shares = market.flop_shares.checked_sub(order.total_shares).unwrap();
-        }
     }
 
     user_trade.total_withdraws = user_trade.total_withdraws.checked_add(current_amount).unwrap();
 
-    market.volume = market.volume.checked_add(current_amount).unwrap();
-    market.opened_orders = market.opened_orders.checked_sub(1).unwrap();
-    market.update_ts = ts;
+    let pnl = (current_amount as i64) - (order.total_amount as i64);
+
+    let user_stats = &mut ctx.accounts.user_stats;
+
+    user_stats.init(ctx.bumps.user_stats, ctx.accounts.signer.key(), market.mint);
+    user_stats.record_close(current_amount, pnl, ts);
+
+    user_stats.record_season_trade(
+        ctx.accounts.season.as_deref_mut(),
+        ctx.accounts.season_score.as_deref_mut(),
+        market.market_id,
+        current_amount,
+        pnl,
+        ts
+    )?;
+
+    require!(
+        !market.has_history || ctx.accounts.market_history.is_some(),
+        TriadProtocolError::MarketHistoryRequired
+    );
+
+    if let Some(market_history) = &ctx.accounts.market_history {
+        market_history.load_mut()?.record_trade(ts, market.hype_price, current_amount);
+    }
 
     let total_amount = order.total_amount;
 
     user_trade.orders[order_index] = Order::default();
 
-    emit!(OrderUpdate {
+    emit_cpi!(OrderUpdate {
         user: *ctx.accounts.signer.key,
         market_id: market.market_id,
+        seq: market.next_event_seq(),
         order_id,
         direction: order.direction,
         order_type: order.order_type,
@@ -155,5 +187,8 @@ pub fn close_order(ctx: Context<CloseOrder>, order_id: u64) -> Result<()> {
             .unwrap_or(-(total_amount as i64)),
     });
 
+    ctx.accounts.market_vault.reload()?;
+    market.check_solvency(ctx.accounts.market_vault.amount)?;
+
     Ok(())
 }
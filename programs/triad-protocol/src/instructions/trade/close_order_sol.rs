@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{ close_account, CloseAccount };
+
+use crate::errors::TriadProtocolError;
+
+use super::close_order::*;
+
+/// `close_order` for SOL-denominated markets: the refund is unwrapped from the signer's wSOL
+/// account back to lamports. An account that already held wSOL is left open, the refund then
+/// stays wrapped in it alongside the existing balance.
+#[derive(Accounts)]
+pub struct CloseOrderSol<'info> {
+    pub order: CloseOrder<'info>,
+}
+
+pub fn close_order_sol<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CloseOrderSol<'info>>,
+    order_id: u64,
+    min_price: u64
+) -> Result<()> {
+    require!(
+        ctx.accounts.order.mint.key() == native_mint::ID,
+        TriadProtocolError::NotNativeMarket
+    );
+
+    let held_before = ctx.accounts.order.user_ata.amount;
+
+    close_order(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.order,
+            ctx.remaining_accounts,
+            ctx.bumps.order
+        ),
+        order_id,
+        min_price
+    )?;
+
+    let order = &ctx.accounts.order;
+
+    // wSOL held before this instruction is left wrapped, closing would unwrap it too
+    if held_before > 0 {
+        return Ok(());
+    }
+
+    close_account(
+        CpiContext::new(order.token_program.to_account_info(), CloseAccount {
+            account: order.user_ata.to_account_info(),
+            destination: order.signer.to_account_info(),
+            authority: order.signer.to_account_info(),
+        })
+    )
+}
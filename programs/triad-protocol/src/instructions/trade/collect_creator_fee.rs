@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{ MarketV2, MarketBond },
+    errors::TriadProtocolError,
+    events::FeeCollected,
+    utils::transfer_checked_with_hook,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CollectCreatorFee<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(
+        seeds = [MarketBond::PREFIX_SEED, market.key().as_ref()],
+        bump = market_bond.bump,
+        constraint = market_bond.creator == signer.key()
+    )]
+    pub market_bond: Box<Account<'info, MarketBond>>,
+
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program
+    )]
+    pub market_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub signer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn collect_creator_fee<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CollectCreatorFee<'info>>
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    let amount = market.market_maker_fee_available
+        .checked_sub(market.market_maker_fee_claimed)
+        .unwrap();
+
+    require!(amount > 0, TriadProtocolError::InsufficientFunds);
+
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.market_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.signer_ata.to_account_info(),
+                authority: market.to_account_info(),
+            },
+            &[&[b"market", &market.market_id.to_le_bytes(), &[market.bump]]]
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.mint.decimals
+    )?;
+
+    market.market_maker_fee_claimed = market.market_maker_fee_claimed.checked_add(amount).unwrap();
+
+    emit_cpi!(FeeCollected {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        authority: *ctx.accounts.signer.key,
+        market_fee: 0,
+        nft_holders_fee: 0,
+        creator_fee: amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    ctx.accounts.market_ata.reload()?;
+    market.check_solvency(ctx.accounts.market_ata.amount)?;
+
+    Ok(())
+}
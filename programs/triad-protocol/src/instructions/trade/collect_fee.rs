@@ -1,9 +1,15 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{ Token2022, transfer_checked, TransferChecked };
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
 use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
 
-use crate::{ state::MarketV2, errors::TriadProtocolError };
+use crate::{
+    state::MarketV2,
+    errors::TriadProtocolError,
+    events::FeeCollected,
+    utils::transfer_checked_with_hook,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CollectFee<'info> {
     #[account(mut)]
@@ -12,7 +18,7 @@ pub struct CollectFee<'info> {
     #[account(mut, constraint = market.authority == signer.key())]
     pub market: Box<Account<'info, MarketV2>>,
 
-    #[account(mut, constraint = mint.key() == market.mint)]
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -31,26 +37,22 @@ pub struct CollectFee<'info> {
     )]
     pub signer_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn collect_fee(ctx: Context<CollectFee>) -> Result<()> {
+pub fn collect_fee<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CollectFee<'info>>
+) -> Result<()> {
     let market = &mut ctx.accounts.market;
 
-    let market_available = market.market_fee_available
-        .checked_sub(market.market_fee_claimed)
-        .unwrap();
-    let holder_available = market.nft_holders_fee_available
-        .checked_sub(market.nft_holders_fee_claimed)
-        .unwrap();
-
-    let amount = market_available.checked_add(holder_available).unwrap();
+    // The NFT holders' share is left in the vault for `fund_nft_reward_pool`
+    let amount = market.market_fee_available.checked_sub(market.market_fee_claimed).unwrap();
 
     require!(amount > 0, TriadProtocolError::InsufficientFunds);
 
-    transfer_checked(
+    transfer_checked_with_hook(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             TransferChecked {
@@ -60,15 +62,25 @@ pub fn collect_fee(ctx: Context<CollectFee>) -> Result<()> {
                 authority: market.to_account_info(),
             },
             &[&[b"market", &market.market_id.to_le_bytes(), &[market.bump]]]
-        ),
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         amount,
         ctx.accounts.mint.decimals
     )?;
 
-    market.market_fee_claimed = market.market_fee_claimed.checked_add(market_available).unwrap();
-    market.nft_holders_fee_claimed = market.nft_holders_fee_claimed
-        .checked_add(holder_available)
-        .unwrap();
+    market.market_fee_claimed = market.market_fee_claimed.checked_add(amount).unwrap();
+
+    emit_cpi!(FeeCollected {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        authority: *ctx.accounts.signer.key,
+        market_fee: amount,
+        nft_holders_fee: 0,
+        creator_fee: 0,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    ctx.accounts.market_ata.reload()?;
+    market.check_solvency(ctx.accounts.market_ata.amount)?;
 
     Ok(())
 }
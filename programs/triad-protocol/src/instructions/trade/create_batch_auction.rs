@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ MarketV2, MarketMode, BatchAuction, CreateBatchAuctionArgs },
+    errors::TriadProtocolError,
+    events::BatchAuctionUpdate,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateBatchAuction<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, constraint = market.authority == signer.key())]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = BatchAuction::SPACE,
+        seeds = [BatchAuction::PREFIX_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub batch_auction: Box<Account<'info, BatchAuction>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_batch_auction(
+    ctx: Context<CreateBatchAuction>,
+    args: CreateBatchAuctionArgs
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    require!(args.batch_duration > 0, TriadProtocolError::InvalidMarketParameters);
+    // Orders already priced by the AMM can't be mixed with batch orders
+    require!(
+        market.mode == MarketMode::Amm && market.opened_orders == 0,
+        TriadProtocolError::InvalidMarketMode
+    );
+
+    market.mode = MarketMode::BatchAuction;
+
+    // The first order opens batch 1, batch 0 marks orders priced by the AMM
+    ctx.accounts.batch_auction.set_inner(BatchAuction {
+        bump: ctx.bumps.batch_auction,
+        market: market.key(),
+        batch_duration: args.batch_duration,
+        batch_id: 0,
+        batch_end: 0,
+        hype_amount: 0,
+        flop_amount: 0,
+        pending_orders: 0,
+        is_clearing: false,
+        clearing_hype_price: 0,
+        clearing_flop_price: 0,
+        padding: [0; 32],
+    });
+
+    let batch_auction = &ctx.accounts.batch_auction;
+
+    emit_cpi!(BatchAuctionUpdate {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        batch_id: batch_auction.batch_id,
+        batch_end: batch_auction.batch_end,
+        hype_amount: batch_auction.hype_amount,
+        flop_amount: batch_auction.flop_amount,
+        pending_orders: batch_auction.pending_orders,
+        is_clearing: batch_auction.is_clearing,
+        clearing_hype_price: batch_auction.clearing_hype_price,
+        clearing_flop_price: batch_auction.clearing_flop_price,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
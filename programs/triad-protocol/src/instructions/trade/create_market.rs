@@ -0,0 +1,198 @@
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{ MarketV2, MarketBond, BondStatus, CreateMarketArgs },
+    errors::TriadProtocolError,
+    events::{ MarketUpdate, MarketBondUpdate },
+    constants::{ ADMIN, MARKET_CREATOR_BOND, MAX_CREATOR_FEE_BPS, MIN_SEED_LIQUIDITY },
+    constraints::is_collateral_mint,
+    utils::{ get_transfer_fee, transfer_checked_with_hook },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: CreateMarketArgs)]
+pub struct CreateMarket<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = MarketV2::SPACE,
+        seeds = [MarketV2::PREFIX_SEED, &args.market_id.to_le_bytes()],
+        bump
+    )]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = MarketBond::SPACE,
+        seeds = [MarketBond::PREFIX_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub market_bond: Box<Account<'info, MarketBond>>,
+
+    #[account(
+        mut,
+        constraint = is_collateral_mint(&mint.key())? @ TriadProtocolError::InvalidMint,
+        mint::token_program = token_program
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_from_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program
+    )]
+    pub market_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = market_bond,
+        associated_token::token_program = token_program
+    )]
+    pub bond_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_market<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CreateMarket<'info>>,
+    args: CreateMarketArgs
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let market_bond = &mut ctx.accounts.market_bond;
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    require!(
+        args.end_time > args.start_time && args.end_time > ts,
+        TriadProtocolError::InvalidMarketParameters
+    );
+    require!(
+        args.seed_liquidity >=
+            MarketV2::scale_amount(MIN_SEED_LIQUIDITY, ctx.accounts.mint.decimals)?,
+        TriadProtocolError::InvalidMarketParameters
+    );
+    require!(
+        args.creator_fee_bps <= MAX_CREATOR_FEE_BPS,
+        TriadProtocolError::InvalidMarketParameters
+    );
+
+    let bond_amount = MarketV2::scale_amount(MARKET_CREATOR_BOND, ctx.accounts.mint.decimals)?;
+
+    // Token-2022 transfer fees are withheld in the receiving accounts, only the remainder is
+    // credited to the bond and the pools
+    let mint_info = ctx.accounts.mint.to_account_info();
+    let bond_received = bond_amount
+        .checked_sub(get_transfer_fee(&mint_info, bond_amount)?)
+        .unwrap();
+    let seed_received = args.seed_liquidity
+        .checked_sub(get_transfer_fee(&mint_info, args.seed_liquidity)?)
+        .unwrap();
+
+    let seed_per_side = seed_received.checked_div(2).unwrap();
+    let seed_liquidity = seed_per_side.checked_mul(2).unwrap();
+
+    market.set_inner(MarketV2 {
+        bump: ctx.bumps.market,
+        authority: Pubkey::from_str(ADMIN).unwrap(),
+        market_id: args.market_id,
+        mint: ctx.accounts.mint.key(),
+        market_start: args.start_time,
+        market_end: args.end_time,
+        question: args.question,
+        creator_fee_bps: args.creator_fee_bps,
+        update_ts: ts,
+        ..Default::default()
+    });
+
+    market.set_collateral_decimals(ctx.accounts.mint.decimals)?;
+
+    // The seed is real collateral and replaces the virtual liquidity
+    market.hype_liquidity = seed_per_side;
+    market.flop_liquidity = seed_per_side;
+    market.market_liquidity_at_start = seed_liquidity;
+
+    market_bond.set_inner(MarketBond {
+        bump: ctx.bumps.market_bond,
+        market: market.key(),
+        creator: ctx.accounts.signer.key(),
+        resolver: args.resolver,
+        amount: bond_received,
+        seed_liquidity,
+        status: BondStatus::Posted,
+        padding: [0; 32],
+    });
+
+    transfer_checked_with_hook(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), TransferChecked {
+            from: ctx.accounts.user_from_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.bond_ata.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+        }).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        bond_amount,
+        ctx.accounts.mint.decimals
+    )?;
+
+    transfer_checked_with_hook(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), TransferChecked {
+            from: ctx.accounts.user_from_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.market_ata.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+        }).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        args.seed_liquidity,
+        ctx.accounts.mint.decimals
+    )?;
+
+    emit_cpi!(MarketUpdate {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        question: String::from_utf8_lossy(&market.question).to_string(),
+        start_time: market.market_start,
+        end_time: market.market_end,
+        hype_liquidity: market.hype_liquidity,
+        flop_liquidity: market.flop_liquidity,
+        winning_direction: market.winning_direction,
+        final_hype_price: market.hype_price,
+        final_flop_price: market.flop_price,
+        timestamp: ts,
+        total_hype_shares: market.hype_shares,
+        total_flop_shares: market.flop_shares,
+        payout_per_share: market.payout_per_share,
+    });
+
+    emit_cpi!(MarketBondUpdate {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        creator: market_bond.creator,
+        resolver: market_bond.resolver,
+        amount: market_bond.amount,
+        status: market_bond.status,
+        timestamp: ts,
+    });
+
+    Ok(())
+}
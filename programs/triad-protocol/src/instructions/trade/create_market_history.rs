@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ MarketV2, MarketHistory, CreateMarketHistoryArgs },
+    errors::TriadProtocolError,
+    events::MarketHistoryUpdate,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateMarketHistory<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = market.authority == signer.key() @ TriadProtocolError::Unauthorized
+    )]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = MarketHistory::SPACE,
+        seeds = [MarketHistory::PREFIX_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub market_history: AccountLoader<'info, MarketHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_market_history(
+    ctx: Context<CreateMarketHistory>,
+    args: CreateMarketHistoryArgs
+) -> Result<()> {
+    require!(args.interval > 0, TriadProtocolError::InvalidMarketParameters);
+
+    let mut market_history = ctx.accounts.market_history.load_init()?;
+
+    market_history.market = ctx.accounts.market.key();
+    market_history.interval = args.interval;
+
+    let market = &mut ctx.accounts.market;
+
+    market.has_history = true;
+
+    emit_cpi!(MarketHistoryUpdate {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        market_history: ctx.accounts.market_history.key(),
+        interval: args.interval,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
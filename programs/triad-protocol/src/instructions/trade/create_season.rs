@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{ Season, SeasonEntry, CreateSeasonArgs },
+    errors::TriadProtocolError,
+    events::SeasonUpdate,
+    constraints::is_admin,
+};
+
+#[derive(Accounts)]
+#[instruction(args: CreateSeasonArgs)]
+pub struct CreateSeason<'info> {
+    #[account(mut, constraint = is_admin(&signer)?)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = Season::SPACE,
+        seeds = [Season::PREFIX_SEED, &args.season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = season,
+        associated_token::token_program = token_program
+    )]
+    pub season_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_season(ctx: Context<CreateSeason>, args: CreateSeasonArgs) -> Result<()> {
+    let mut eligible_markets = [0u64; 16];
+    let mut prize_bps = [0u16; 10];
+
+    require!(args.end_ts > args.start_ts, TriadProtocolError::InvalidSeason);
+    require!(
+        args.eligible_markets.len() <= eligible_markets.len() &&
+            args.prize_bps.len() <= prize_bps.len() &&
+            args.prize_bps
+                .iter()
+                .map(|bps| *bps as u64)
+                .sum::<u64>() <= 10000,
+        TriadProtocolError::InvalidSeason
+    );
+
+    eligible_markets[..args.eligible_markets.len()].copy_from_slice(&args.eligible_markets);
+    prize_bps[..args.prize_bps.len()].copy_from_slice(&args.prize_bps);
+
+    ctx.accounts.season.set_inner(Season {
+        bump: ctx.bumps.season,
+        authority: *ctx.accounts.signer.key,
+        season_id: args.season_id,
+        mint: ctx.accounts.mint.key(),
+        start_ts: args.start_ts,
+        end_ts: args.end_ts,
+        score_type: args.score_type,
+        eligible_markets,
+        eligible_markets_len: args.eligible_markets.len() as u8,
+        prize_pool: 0,
+        prize_bps,
+        claimed_ranks: 0,
+        leaderboard: [SeasonEntry::default(); 10],
+        settled_prize_pool: 0,
+        padding: [0; 24],
+    });
+
+    emit!(SeasonUpdate {
+        season_id: args.season_id,
+        start_ts: args.start_ts,
+        end_ts: args.end_ts,
+        prize_pool: 0,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
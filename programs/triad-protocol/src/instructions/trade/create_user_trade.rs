@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
 
-use crate::{ state::{ User, UserTrade, Order }, constraints::is_authority_for_user };
+use crate::{
+    state::{ User, UserTrade, Order },
+    events::UserTradeUpdate,
+    constraints::is_authority_for_user,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CreateUserTrade<'info> {
     #[account(mut)]
@@ -38,5 +43,12 @@ pub fn create_user_trade(ctx: Context<CreateUserTrade>) -> Result<()> {
 
     user.user_trade = user_trade.key();
 
+    emit_cpi!(UserTradeUpdate {
+        user: user.key(),
+        authority: user_trade.authority,
+        user_trade: user_trade.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
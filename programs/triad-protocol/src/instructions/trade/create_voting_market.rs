@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{ transfer, Transfer };
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    state::{ VotingMarket, CreateVotingMarketArgs },
+    errors::TriadProtocolError,
+    events::VotingMarketUpdate,
+    constants::{ ADMIN, TRD_MINT, VOTING_MARKET_FEE, VOTING_MARKET_MIN_VOTES },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: CreateVotingMarketArgs)]
+pub struct CreateVotingMarket<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = VotingMarket::SPACE,
+        seeds = [VotingMarket::PREFIX_SEED, &args.vote_id.to_le_bytes()],
+        bump
+    )]
+    pub voting_market: Box<Account<'info, VotingMarket>>,
+
+    #[account(
+        constraint = mint.key().to_string() == TRD_MINT @ TriadProtocolError::InvalidMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Admin Account
+    #[account(mut, constraint = admin.key.to_string() == ADMIN)]
+    pub admin: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_voting_market(
+    ctx: Context<CreateVotingMarket>,
+    args: CreateVotingMarketArgs
+) -> Result<()> {
+    let voting_market = &mut ctx.accounts.voting_market;
+
+    transfer(
+        CpiContext::new(ctx.accounts.system_program.to_account_info(), Transfer {
+            from: ctx.accounts.signer.to_account_info(),
+            to: ctx.accounts.admin.to_account_info(),
+        }),
+        VOTING_MARKET_FEE
+    )?;
+
+    let voting_start = Clock::get()?.unix_timestamp;
+    let voting_end = voting_start + VotingMarket::VOTING_PERIOD;
+    let market_start = voting_end + VotingMarket::MARKET_START_DELAY;
+    let market_end = market_start + VotingMarket::MARKET_PERIOD;
+    let resolve_ts = market_end + VotingMarket::RESOLVE_DELAY;
+
+    voting_market.set_inner(VotingMarket {
+        bump: ctx.bumps.voting_market,
+        authority: ctx.accounts.signer.key(),
+        vote_id: args.vote_id,
+        question: args.question,
+        resolution_source: args.resolution_source,
+        mint: ctx.accounts.mint.key(),
+        voting_start,
+        voting_end,
+        market_start,
+        market_end,
+        resolve_ts,
+        // The proposer's own vote
+        total_hype: 1,
+        total_flop: 0,
+        min_votes: VOTING_MARKET_MIN_VOTES,
+        market_id: 0,
+        is_promoted: false,
+        padding: [0; 64],
+    });
+
+    let ts = voting_start;
+
+    emit_cpi!(VotingMarketUpdate {
+        voting_market: voting_market.key(),
+        vote_id: voting_market.vote_id,
+        authority: voting_market.authority,
+        question: String::from_utf8_lossy(&voting_market.question).to_string(),
+        market_id: voting_market.market_id,
+        voting_end: voting_market.voting_end,
+        market_start: voting_market.market_start,
+        market_end: voting_market.market_end,
+        total_hype: voting_market.total_hype,
+        total_flop: voting_market.total_flop,
+        is_promoted: voting_market.is_promoted,
+        timestamp: ts,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{
+        MarketV2,
+        MarketMode,
+        MarketHistory,
+        UserStats,
+        Season,
+        SeasonScore,
+        UserTrade,
+        OrderStatus,
+        Order,
+    },
+    errors::TriadProtocolError,
+    events::{ OrderUpdate, OrderTriggered },
+    constants::TRIGGER_KEEPER_FEE_BPS,
+    utils::transfer_checked_with_hook,
+};
+
+/// Permissionless crank closing an order whose stop-loss or take-profit has been crossed.
+///
+/// The refund goes to the order owner, minus `TRIGGER_KEEPER_FEE_BPS` paid to the keeper.
+/// Triggers fire on a price no trade has moved during the current second.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteOrderTrigger<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(address = user_trade.authority)]
+    pub authority: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub user_trade: Box<Account<'info, UserTrade>>,
+
+    /// Trading stats of the order owner
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = UserStats::SPACE,
+        seeds = [UserStats::PREFIX_SEED, authority.key().as_ref(), market.mint.as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    /// Candle history, required once the market has one
+    #[account(mut, has_one = market)]
+    pub market_history: Option<AccountLoader<'info, MarketHistory>>,
+
+    /// Season joined by the order owner, required while it runs, with their score
+    #[account(mut)]
+    pub season: Option<Box<Account<'info, Season>>>,
+
+    #[account(mut, constraint = season_score.authority == authority.key())]
+    pub season_score: Option<Box<Account<'info, SeasonScore>>>,
+
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub keeper_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_order_trigger<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ExecuteOrderTrigger<'info>>,
+    order_id: u64
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let user_trade = &mut ctx.accounts.user_trade;
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    require!(market.is_active, TriadProtocolError::MarketInactive);
+    require!(market.mode == MarketMode::Amm, TriadProtocolError::InvalidMarketMode);
+    require!(ts > market.market_start, TriadProtocolError::QuestionPeriodNotStarted);
+    require!(market.market_end > ts, TriadProtocolError::QuestionPeriodEnded);
+
+    let order_index = user_trade.orders
+        .iter()
+        .position(
+            |order|
+                order.order_id == order_id &&
+                order.market_id == market.market_id &&
+                order.status == OrderStatus::Open
+        )
+        .ok_or(TriadProtocolError::OrderNotFound)?;
+
+    let order = user_trade.orders[order_index];
+
+    // Triggers fire on the TWAP rather than the spot price, so the price cannot be pushed
+    // across the trigger and back around the execution in a single transaction
+    let trigger_price = market.trigger_price(order.direction, ts)?;
+
+    require!(order.is_triggered(trigger_price), TriadProtocolError::OrderTriggerNotReached);
+
+    let current_price = market.price(order.direction);
+
+    let (current_amount, price_update) = market.close_position(&order, ts)?;
+
+    if let Some(price_update) = price_update {
+        emit_cpi!(price_update);
+    }
+
+    let keeper_fee = current_amount
+        .checked_mul(TRIGGER_KEEPER_FEE_BPS)
+        .unwrap()
+        .checked_div(10000)
+        .unwrap();
+    let net_amount = current_amount.checked_sub(keeper_fee).unwrap();
+
+    let signer: &[&[&[u8]]] = &[&[b"market", &market.market_id.to_le_bytes(), &[market.bump]]];
+
+    for (to, amount) in [
+        (ctx.accounts.user_ata.to_account_info(), net_amount),
+        (ctx.accounts.keeper_ata.to_account_info(), keeper_fee),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+
+        transfer_checked_with_hook(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.market_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to,
+                    authority: market.to_account_info(),
+                },
+                signer
+            ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            amount,
+            ctx.accounts.mint.decimals
+        )?;
+    }
+
+    user_trade.total_withdraws = user_trade.total_withdraws.checked_add(net_amount).unwrap();
+
+    let pnl = (net_amount as i64) - (order.total_amount as i64);
+
+    let user_stats = &mut ctx.accounts.user_stats;
+
+    user_stats.init(ctx.bumps.user_stats, user_trade.authority, market.mint);
+    user_stats.record_close(net_amount, pnl, ts);
+
+    user_stats.record_season_trade(
+        ctx.accounts.season.as_deref_mut(),
+        ctx.accounts.season_score.as_deref_mut(),
+        market.market_id,
+        net_amount,
+        pnl,
+        ts
+    )?;
+
+    require!(
+        !market.has_history || ctx.accounts.market_history.is_some(),
+        TriadProtocolError::MarketHistoryRequired
+    );
+
+    if let Some(market_history) = &ctx.accounts.market_history {
+        market_history.load_mut()?.record_trade(ts, market.hype_price, current_amount);
+    }
+
+    user_trade.orders[order_index] = Order::default();
+
+    emit_cpi!(OrderUpdate {
+        user: user_trade.authority,
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        order_id,
+        direction: order.direction,
+        order_type: order.order_type,
+        question_id: order.question_id,
+        order_status: OrderStatus::Closed,
+        price: current_price,
+        total_shares: order.total_shares,
+        total_amount: order.total_amount,
+        refund_amount: Some(net_amount),
+        timestamp: ts,
+        is_question_winner: None,
+        pnl,
+    });
+
+    emit_cpi!(OrderTriggered {
+        user: user_trade.authority,
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        order_id,
+        keeper: *ctx.accounts.signer.key,
+        price: current_price,
+        keeper_fee,
+        timestamp: ts,
+    });
+
+    ctx.accounts.market_vault.reload()?;
+    market.check_solvency(ctx.accounts.market_vault.amount)?;
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{ Season, FundSeasonArgs },
+    errors::TriadProtocolError,
+    events::SeasonUpdate,
+    utils::transfer_checked_with_hook,
+};
+
+#[derive(Accounts)]
+#[instruction(args: FundSeasonArgs)]
+pub struct FundSeason<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub season: Box<Account<'info, Season>>,
+
+    #[account(constraint = mint.key() == season.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = user_from_ata.amount >= args.amount,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_from_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = season,
+        associated_token::token_program = token_program
+    )]
+    pub season_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn fund_season<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, FundSeason<'info>>,
+    args: FundSeasonArgs
+) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    require!(args.amount > 0, TriadProtocolError::InsufficientFunds);
+    // Prizes are shared out of the pool as it stands at the end of the season
+    require!(ts < season.end_ts, TriadProtocolError::InvalidSeason);
+
+    transfer_checked_with_hook(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), TransferChecked {
+            from: ctx.accounts.user_from_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.season_ata.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+        }).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        args.amount,
+        ctx.accounts.mint.decimals
+    )?;
+
+    season.prize_pool = season.prize_pool.checked_add(args.amount).unwrap();
+
+    emit!(SeasonUpdate {
+        season_id: season.season_id,
+        start_ts: season.start_ts,
+        end_ts: season.end_ts,
+        prize_pool: season.prize_pool,
+        timestamp: ts,
+    });
+
+    Ok(())
+}
@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::TokenInterface;
 use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
 
-use crate::{ state::{ MarketV2, InitializeMarketArgs }, constraints::is_admin };
+use crate::{
+    state::{ MarketV2, InitializeMarketArgs },
+    constraints::is_admin,
+    events::MarketUpdate,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: InitializeMarketArgs)]
 pub struct InitializeMarket<'info> {
@@ -19,7 +24,7 @@ pub struct InitializeMarket<'info> {
     )]
     pub market: Box<Account<'info, MarketV2>>,
 
-    #[account(mut)]
+    #[account(mut, mint::token_program = token_program)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -31,7 +36,7 @@ pub struct InitializeMarket<'info> {
     )]
     pub market_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -53,5 +58,24 @@ pub fn initialize_market(ctx: Context<InitializeMarket>, args: InitializeMarketA
         ..Default::default()
     });
 
+    market.set_collateral_decimals(ctx.accounts.mint.decimals)?;
+
+    emit_cpi!(MarketUpdate {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        question: String::from_utf8_lossy(&market.question).to_string(),
+        start_time: market.market_start,
+        end_time: market.market_end,
+        hype_liquidity: market.hype_liquidity,
+        flop_liquidity: market.flop_liquidity,
+        winning_direction: market.winning_direction,
+        final_hype_price: market.hype_price,
+        final_flop_price: market.flop_price,
+        timestamp: ts,
+        total_hype_shares: market.hype_shares,
+        total_flop_shares: market.flop_shares,
+        payout_per_share: market.payout_per_share,
+    });
+
     Ok(())
 }
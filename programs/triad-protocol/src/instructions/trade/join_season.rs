@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ Season, SeasonScore, UserStats },
+    errors::TriadProtocolError,
+    events::SeasonJoined,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct JoinSeason<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub season: Box<Account<'info, Season>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = SeasonScore::SPACE,
+        seeds = [SeasonScore::PREFIX_SEED, season.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub season_score: Box<Account<'info, SeasonScore>>,
+
+    /// Stats of the signer in the season mint, every later trade in it must be scored
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = UserStats::SPACE,
+        seeds = [UserStats::PREFIX_SEED, signer.key().as_ref(), season.mint.as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn join_season(ctx: Context<JoinSeason>) -> Result<()> {
+    let season = &ctx.accounts.season;
+    let user_stats = &mut ctx.accounts.user_stats;
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    require!(ts < season.end_ts, TriadProtocolError::InvalidSeason);
+    // One running season per mint, so every trade has a single season to be scored in
+    require!(!user_stats.in_season(ts), TriadProtocolError::SeasonAlreadyJoined);
+
+    ctx.accounts.season_score.set_inner(SeasonScore {
+        bump: ctx.bumps.season_score,
+        season_id: season.season_id,
+        authority: *ctx.accounts.signer.key,
+        score: 0,
+        volume: 0,
+        pnl: 0,
+    });
+
+    user_stats.init(ctx.bumps.user_stats, ctx.accounts.signer.key(), season.mint);
+    user_stats.season = season.key();
+    user_stats.season_end_ts = season.end_ts;
+
+    emit_cpi!(SeasonJoined {
+        season_id: season.season_id,
+        user: *ctx.accounts.signer.key,
+        end_ts: season.end_ts,
+        timestamp: ts,
+    });
+
+    Ok(())
+}
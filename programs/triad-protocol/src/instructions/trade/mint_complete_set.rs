@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{
+        MarketV2,
+        UserTrade,
+        Order,
+        OrderDirection,
+        OrderStatus,
+        OrderType,
+        MintCompleteSetArgs,
+        WinningDirection,
+    },
+    errors::TriadProtocolError,
+    events::OrderUpdate,
+    constraints::is_authority_for_user_trade,
+    utils::{ get_transfer_fee, transfer_checked_with_hook },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: MintCompleteSetArgs)]
+pub struct MintCompleteSet<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = is_authority_for_user_trade(&user_trade, &signer)?
+    )]
+    pub user_trade: Box<Account<'info, UserTrade>>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = user_from_ata.amount >= args.amount,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_from_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program
+    )]
+    pub market_to_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn mint_complete_set<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, MintCompleteSet<'info>>,
+    args: MintCompleteSetArgs
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let user_trade = &mut ctx.accounts.user_trade;
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    require!(market.is_active, TriadProtocolError::MarketInactive);
+    require!(
+        market.winning_direction == WinningDirection::None,
+        TriadProtocolError::MarketAlreadyResolved
+    );
+    require!(args.amount > 0, TriadProtocolError::InsufficientFunds);
+
+    // Token-2022 transfer fees are withheld in the vault, only the remainder backs the set
+    let transfer_fee = get_transfer_fee(&ctx.accounts.mint.to_account_info(), args.amount)?;
+    let amount = args.amount.checked_sub(transfer_fee).unwrap();
+
+    require!(amount > 0, TriadProtocolError::InsufficientFunds);
+
+    market.check_position_limits(user_trade.market_stake(market.market_id), amount)?;
+
+    // Split the collateral at the current prices so the pool ratio, and therefore the
+    // AMM price, is left untouched
+    let hype_amount = amount
+        .checked_mul(market.hype_price)
+        .unwrap()
+        .checked_div(1_000_000)
+        .unwrap();
+    let flop_amount = amount.checked_sub(hype_amount).unwrap();
+
+    let mut free_slots = user_trade.orders
+        .iter()
+        .enumerate()
+        .filter(|(_, order)| !order.is_active())
+        .map(|(index, _)| index);
+
+    let hype_index = free_slots.next().ok_or(TriadProtocolError::NoAvailableOrderSlot)?;
+    let flop_index = free_slots.next().ok_or(TriadProtocolError::NoAvailableOrderSlot)?;
+
+    let legs = [
+        (hype_index, OrderDirection::Hype, market.hype_price, hype_amount),
+        (flop_index, OrderDirection::Flop, market.flop_price, flop_amount),
+    ];
+
+    for (order_index, direction, price, leg_amount) in legs {
+        user_trade.orders[order_index] = Order {
+            ts,
+            order_id: market.next_order_id(),
+            question_id: 0,
+            market_id: market.market_id,
+            status: OrderStatus::Open,
+            price,
+            total_amount: leg_amount,
+            total_shares: amount,
+            order_type: OrderType::Market,
+            direction,
+            stop_loss_price: 0,
+            take_profit_price: 0,
+            batch_id: 0,
+            padding: [0; 8],
+        };
+    }
+
+    market.hype_liquidity = market.hype_liquidity.checked_add(hype_amount).unwrap();
+    market.flop_liquidity = market.flop_liquidity.checked_add(flop_amount).unwrap();
+    market.hype_shares = market.hype_shares.checked_add(amount).unwrap();
+    market.flop_shares = market.flop_shares.checked_add(amount).unwrap();
+    market.opened_orders = market.opened_orders.checked_add(2).unwrap();
+    market.open_interest = market.open_interest.checked_add(amount).unwrap();
+
+    user_trade.opened_orders = user_trade.opened_orders.checked_add(2).unwrap();
+    user_trade.total_deposits = user_trade.total_deposits.checked_add(amount).unwrap();
+
+    // Sets are fee free, so they are left out of the user stats and season scores rather than
+    // letting mint/redeem loops farm volume
+
+    transfer_checked_with_hook(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), TransferChecked {
+            from: ctx.accounts.user_from_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.market_to_ata.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+        }).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        args.amount,
+        ctx.accounts.mint.decimals
+    )?;
+
+    for (order_index, _, _, _) in legs {
+        let current_order = user_trade.orders[order_index];
+
+        emit_cpi!(OrderUpdate {
+            timestamp: current_order.ts,
+            user: user_trade.authority,
+            question_id: current_order.question_id,
+            market_id: current_order.market_id,
+            seq: market.next_event_seq(),
+            order_id: current_order.order_id,
+            direction: current_order.direction,
+            order_type: current_order.order_type,
+            order_status: current_order.status,
+            total_shares: current_order.total_shares,
+            total_amount: current_order.total_amount,
+            pnl: 0,
+            price: current_order.price,
+            refund_amount: None,
+            is_question_winner: None,
+        });
+    }
+
+    ctx.accounts.market_to_ata.reload()?;
+    market.check_solvency(ctx.accounts.market_to_ata.amount)?;
+
+    Ok(())
+}
@@ -7,6 +7,35 @@ mod v1;
 mod add_liquidity;
 mod collect_fee;
 mod payout_order;
+mod payout_orders;
+mod payout_all;
+mod verify_market;
+mod create_voting_market;
+mod vote;
+mod promote_voting_market;
+mod create_market;
+mod void_market;
+mod settle_market_bond;
+mod collect_creator_fee;
+mod update_market_limits;
+mod observe_price;
+mod create_market_history;
+mod create_season;
+mod fund_season;
+mod join_season;
+mod claim_season_prize;
+mod create_market_metadata;
+mod update_market_metadata;
+mod mint_complete_set;
+mod redeem_complete_set;
+mod open_order_sol;
+mod close_order_sol;
+mod payout_order_sol;
+mod set_order_triggers;
+mod execute_order_trigger;
+mod create_batch_auction;
+mod submit_batch_order;
+mod clear_batch;
 
 pub use close_order::*;
 pub use create_user_trade::*;
@@ -17,3 +46,32 @@ pub use v1::*;
 pub use add_liquidity::*;
 pub use collect_fee::*;
 pub use payout_order::*;
+pub use payout_orders::*;
+pub use payout_all::*;
+pub use verify_market::*;
+pub use create_voting_market::*;
+pub use vote::*;
+pub use promote_voting_market::*;
+pub use create_market::*;
+pub use void_market::*;
+pub use settle_market_bond::*;
+pub use collect_creator_fee::*;
+pub use update_market_limits::*;
+pub use observe_price::*;
+pub use create_market_history::*;
+pub use create_season::*;
+pub use fund_season::*;
+pub use join_season::*;
+pub use claim_season_prize::*;
+pub use create_market_metadata::*;
+pub use update_market_metadata::*;
+pub use mint_complete_set::*;
+pub use redeem_complete_set::*;
+pub use open_order_sol::*;
+pub use close_order_sol::*;
+pub use payout_order_sol::*;
+pub use set_order_triggers::*;
+pub use execute_order_trigger::*;
+pub use create_batch_auction::*;
+pub use submit_batch_order::*;
+pub use clear_batch::*;
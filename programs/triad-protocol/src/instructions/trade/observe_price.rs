@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ MarketV2, PriceObservation };
+
+#[derive(Accounts)]
+pub struct ObservePrice<'info> {
+    pub market: Box<Account<'info, MarketV2>>,
+}
+
+/// Returns the current price accumulator so other programs can compute a TWAP from two
+/// observations with `PriceObservation::twap`
+pub fn observe_price(ctx: Context<ObservePrice>) -> Result<PriceObservation> {
+    Ok(ctx.accounts.market.observe(Clock::get()?.unix_timestamp))
+}
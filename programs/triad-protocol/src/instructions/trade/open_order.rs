@@ -1,14 +1,34 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{ Token2022, transfer_checked, TransferChecked };
-use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{
+    associated_token::{ get_associated_token_address_with_program_id, AssociatedToken },
+    token_interface::{ Mint, TokenAccount },
+};
 
 use crate::{
-    state::{ MarketV2, UserTrade, Order, OrderDirection, OrderStatus, OrderType, OpenOrderArgs },
+    state::{
+        MarketV2,
+        MarketMode,
+        MarketHistory,
+        UserTrade,
+        User,
+        UserStats,
+        Season,
+        SeasonScore,
+        ReferralRewards,
+        Order,
+        OrderDirection,
+        OrderStatus,
+        OrderType,
+        OpenOrderArgs,
+    },
     errors::TriadProtocolError,
-    events::OrderUpdate,
+    events::{ OrderUpdate, ReferralFeeUpdate },
     constraints::is_authority_for_user_trade,
+    utils::{ get_transfer_fee, transfer_checked_with_hook },
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: OpenOrderArgs)]
 pub struct OpenOrder<'info> {
@@ -21,15 +41,35 @@ pub struct OpenOrder<'info> {
     )]
     pub user_trade: Box<Account<'info, UserTrade>>,
 
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = UserStats::SPACE,
+        seeds = [UserStats::PREFIX_SEED, signer.key().as_ref(), market.mint.as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
     #[account(mut)]
     pub market: Box<Account<'info, MarketV2>>,
 
-    #[account(mut, constraint = mint.key() == market.mint)]
+    /// Candle history, required once the market has one
+    #[account(mut, has_one = market)]
+    pub market_history: Option<AccountLoader<'info, MarketHistory>>,
+
+    /// Season joined by the signer, required while it runs, with their score
+    #[account(mut)]
+    pub season: Option<Box<Account<'info, Season>>>,
+
+    #[account(mut, constraint = season_score.authority == signer.key())]
+    pub season_score: Option<Box<Account<'info, SeasonScore>>>,
+
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
-        mut, 
-        constraint = user_from_ata.amount >= args.amount,
+        init_if_needed,
+        payer = signer,
         associated_token::mint = mint,
         associated_token::authority = signer,
         associated_token::token_program = token_program
@@ -45,35 +85,71 @@ pub struct OpenOrder<'info> {
     )]
     pub market_to_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token2022>,
+    /// Trader's `User`, with the two accounts below credits its referrer when provided
+    #[account(constraint = user.authority == signer.key() @ TriadProtocolError::InvalidReferral)]
+    pub user: Option<Box<Account<'info, User>>>,
+
+    #[account(
+        mut,
+        seeds = [
+            ReferralRewards::PREFIX_SEED,
+            referral_rewards.user.as_ref(),
+            market.mint.as_ref(),
+        ],
+        bump = referral_rewards.bump
+    )]
+    pub referral_rewards: Option<Box<Account<'info, ReferralRewards>>>,
+
+    #[account(mut)]
+    pub referral_rewards_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn open_order(ctx: Context<OpenOrder>, args: OpenOrderArgs) -> Result<()> {
+pub fn open_order<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, OpenOrder<'info>>,
+    args: OpenOrderArgs
+) -> Result<()> {
     let market = &mut ctx.accounts.market;
     let user_trade = &mut ctx.accounts.user_trade;
 
     let ts = Clock::get()?.unix_timestamp;
 
     require!(market.is_active, TriadProtocolError::MarketInactive);
+    require!(market.mode == MarketMode::Amm, TriadProtocolError::InvalidMarketMode);
     require!(ts > market.market_start, TriadProtocolError::QuestionPeriodNotStarted);
     require!(market.market_end > ts, TriadProtocolError::QuestionPeriodEnded);
+    require!(
+        ctx.accounts.user_from_ata.amount >= args.amount,
+        TriadProtocolError::InsufficientFunds
+    );
 
     let (current_price, current_liquidity, otherside_current_liquidity) = match args.direction {
         OrderDirection::Hype => (market.hype_price, market.hype_liquidity, market.flop_liquidity),
         OrderDirection::Flop => (market.flop_price, market.flop_liquidity, market.hype_liquidity),
     };
 
-    require!(ts > market.update_ts, TriadProtocolError::ConcurrentTransaction);
     require!(current_price > 0, TriadProtocolError::InvalidPrice);
     require!(current_liquidity > 0, TriadProtocolError::InsufficientLiquidity);
     require!(otherside_current_liquidity > 0, TriadProtocolError::InsufficientLiquidity);
 
-    let fee_amount = ((args.amount * (market.fee_bps as u64)) / 100000) as u64;
-    let net_amount = args.amount.saturating_sub(fee_amount);
+    // Token-2022 transfer fees are withheld in the vault, only the remainder is tradeable
+    let transfer_fee = get_transfer_fee(&ctx.accounts.mint.to_account_info(), args.amount)?;
+    let received_amount = args.amount.checked_sub(transfer_fee).unwrap();
+
+    let fee_amount = (received_amount * (market.fee_bps as u64)) / 100000;
+    let net_amount = received_amount.saturating_sub(fee_amount);
+
+    // Enough collateral for at least one whole share at the current price
+    require!(
+        net_amount > MarketV2::scale_amount(current_price, market.decimals())?,
+        TriadProtocolError::InsufficientFunds
+    );
 
-    require!(net_amount > current_price, TriadProtocolError::InsufficientFunds);
+    market.check_order_size(net_amount)?;
+    market.check_position_limits(user_trade.market_stake(market.market_id), net_amount)?;
 
     let new_directional_liquidity = current_liquidity.checked_add(net_amount).unwrap();
     let markets_liquidity = new_directional_liquidity
@@ -87,15 +163,21 @@ pub fn open_order(ctx: Context<OpenOrder>, args: OpenOrderArgs) -> Result<()> {
         .unwrap()
         .clamp(1, 999_999);
 
+    // Trades in the same slot fill one after another, so bound the price this one lands at
+    require!(args.max_price > 0, TriadProtocolError::InvalidPrice);
+    require!(new_price <= args.max_price, TriadProtocolError::SlippageExceeded);
+
     let total_shares = net_amount.checked_mul(1_000_000).unwrap().checked_div(new_price).unwrap();
 
     if total_shares.eq(&0) {
         return Err(TriadProtocolError::InsufficientFunds.into());
     }
 
+    let is_new_market = user_trade.market_stake(market.market_id) == 0;
+
     let order_index = user_trade.orders
         .iter()
-        .position(|order| order.status != OrderStatus::Open)
+        .position(|order| !order.is_active())
         .ok_or(TriadProtocolError::NoAvailableOrderSlot)?;
 
     user_trade.orders[order_index] = Order {
@@ -109,15 +191,33 @@ pub fn open_order(ctx: Context<OpenOrder>, args: OpenOrderArgs) -> Result<()> {
         total_shares,
         order_type: OrderType::Market,
         direction: args.direction,
-        padding: [0; 32],
+        stop_loss_price: 0,
+        take_profit_price: 0,
+        batch_id: 0,
+        padding: [0; 8],
     };
 
     user_trade.opened_orders = user_trade.opened_orders.checked_add(1).unwrap();
     user_trade.total_deposits = user_trade.total_deposits.checked_add(net_amount).unwrap();
 
+    let user_stats = &mut ctx.accounts.user_stats;
+
+    user_stats.init(ctx.bumps.user_stats, ctx.accounts.signer.key(), market.mint);
+    user_stats.record_open(net_amount, fee_amount, is_new_market, ts);
+
+    user_stats.record_season_trade(
+        ctx.accounts.season.as_deref_mut(),
+        ctx.accounts.season_score.as_deref_mut(),
+        market.market_id,
+        net_amount,
+        0,
+        ts
+    )?;
+
     market.opened_orders = market.opened_orders.checked_add(1).unwrap();
     market.volume = market.volume.checked_add(net_amount).unwrap();
-    market.update_ts = ts;
+    market.open_interest = market.open_interest.checked_add(net_amount).unwrap();
+    market.accumulate_price(ts);
 
     // Update market shares
     match args.direction {
@@ -129,43 +229,123 @@ pub fn open_order(ctx: Context<OpenOrder>, args: OpenOrderArgs) -> Result<()> {
         }
     }
 
-    market.update_price(net_amount, new_price, args.direction, true)?;
+    let price_update = market.update_price(net_amount, new_price, args.direction, true)?;
+
+    emit_cpi!(price_update);
+
+    require!(
+        !market.has_history || ctx.accounts.market_history.is_some(),
+        TriadProtocolError::MarketHistoryRequired
+    );
+
+    if let Some(market_history) = &ctx.accounts.market_history {
+        market_history.load_mut()?.record_trade(ts, market.hype_price, net_amount);
+    }
 
     // Calculate fee distribution
     let nft_holders_fee = (fee_amount * 100) / 10000; // 0.1%
-    // let Market_Maker_fee = (fee_amount * 100) / 10000; // 0.1%
-
-    
     let market_fee = fee_amount - nft_holders_fee; // Remaining 2% fee
-    // let market_fee = fee_amount - nft_holders_fee - Market_Market_fee // remaining 1,9%
-    
+    let creator_fee = (market_fee * (market.creator_fee_bps as u64)) / 10000;
+
+    let referral = match
+        (&ctx.accounts.user, &mut ctx.accounts.referral_rewards, &ctx.accounts.referral_rewards_ata)
+    {
+        (Some(user), Some(referral_rewards), Some(referral_rewards_ata)) => {
+            require!(
+                referral_rewards.user == user.referral &&
+                    referral_rewards.mint == market.mint &&
+                    referral_rewards_ata.key() ==
+                        get_associated_token_address_with_program_id(
+                            &referral_rewards.key(),
+                            &market.mint,
+                            &ctx.accounts.token_program.key()
+                        ),
+                TriadProtocolError::InvalidReferral
+            );
+
+            Some((referral_rewards, referral_rewards_ata))
+        }
+        (None, None, None) => None,
+        _ => {
+            return Err(TriadProtocolError::InvalidReferral.into());
+        }
+    };
+
+    let referral_fee = if referral.is_some() {
+        (market_fee * (market.referral_fee_bps as u64)) / 10000
+    } else {
+        0
+    };
+
     market.nft_holders_fee_available = market.nft_holders_fee_available
         .checked_add(nft_holders_fee)
         .unwrap();
-    market.market_fee_available = market.market_fee_available.checked_add(market_fee).unwrap();
-
-    //market.market_maker_fee_available = market.nft_holders_fee_available
-      //  .checked_add(Market_Maker_fee)
-       // .unwrap();
+    market.market_fee_available = market.market_fee_available
+        .checked_add(market_fee - creator_fee - referral_fee)
+        .unwrap();
+    market.market_maker_fee_available = market.market_maker_fee_available
+        .checked_add(creator_fee)
+        .unwrap();
 
-    transfer_checked(
+    transfer_checked_with_hook(
         CpiContext::new(ctx.accounts.token_program.to_account_info(), TransferChecked {
             from: ctx.accounts.user_from_ata.to_account_info(),
             mint: ctx.accounts.mint.to_account_info(),
             to: ctx.accounts.market_to_ata.to_account_info(),
             authority: ctx.accounts.signer.to_account_info(),
-        }),
-        net_amount.checked_add(fee_amount).unwrap(),
+        }).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        args.amount,
         ctx.accounts.mint.decimals
     )?;
 
+    if let Some((referral_rewards, referral_rewards_ata)) = referral {
+        if referral_fee > 0 {
+            let signer: &[&[&[u8]]] = &[
+                &[b"market", &market.market_id.to_le_bytes(), &[market.bump]],
+            ];
+
+            transfer_checked_with_hook(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.market_to_ata.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        to: referral_rewards_ata.to_account_info(),
+                        authority: market.to_account_info(),
+                    },
+                    signer
+                ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+                referral_fee,
+                ctx.accounts.mint.decimals
+            )?;
+
+            let referral_received = referral_fee
+                .checked_sub(get_transfer_fee(&ctx.accounts.mint.to_account_info(), referral_fee)?)
+                .unwrap();
+
+            referral_rewards.earned = referral_rewards.earned
+                .checked_add(referral_received)
+                .unwrap();
+
+            emit_cpi!(ReferralFeeUpdate {
+                market_id: market.market_id,
+                seq: market.next_event_seq(),
+                referrer: referral_rewards.user,
+                trader: *ctx.accounts.signer.key,
+                amount: referral_received,
+                timestamp: ts,
+            });
+        }
+    }
+
     let current_order = user_trade.orders[order_index];
 
-    emit!(OrderUpdate {
+    emit_cpi!(OrderUpdate {
         timestamp: current_order.ts,
         user: user_trade.authority,
         question_id: current_order.question_id,
         market_id: current_order.market_id,
+        seq: market.next_event_seq(),
         order_id: current_order.order_id,
         direction: current_order.direction,
         order_type: current_order.order_type,
@@ -178,5 +358,8 @@ pub fn open_order(ctx: Context<OpenOrder>, args: OpenOrderArgs) -> Result<()> {
         is_question_winner: None,
     });
 
+    ctx.accounts.market_to_ata.reload()?;
+    market.check_solvency(ctx.accounts.market_to_ata.amount)?;
+
     Ok(())
 }
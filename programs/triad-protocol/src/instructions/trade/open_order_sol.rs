@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{ transfer, Transfer };
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{ close_account, sync_native, CloseAccount, SyncNative };
+
+use crate::{ state::OpenOrderArgs, errors::TriadProtocolError };
+
+use super::open_order::*;
+
+/// `open_order` for SOL-denominated markets: wraps `args.amount` lamports from the signer
+/// into their wSOL account and opens the order. The wSOL account is closed afterwards unless it
+/// already held wSOL, which is then left wrapped untouched.
+#[derive(Accounts)]
+pub struct OpenOrderSol<'info> {
+    pub order: OpenOrder<'info>,
+}
+
+pub fn open_order_sol<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, OpenOrderSol<'info>>,
+    args: OpenOrderArgs
+) -> Result<()> {
+    let order = &mut ctx.accounts.order;
+
+    require!(order.mint.key() == native_mint::ID, TriadProtocolError::NotNativeMarket);
+
+    let held_before = order.user_from_ata.amount;
+
+    transfer(
+        CpiContext::new(order.system_program.to_account_info(), Transfer {
+            from: order.signer.to_account_info(),
+            to: order.user_from_ata.to_account_info(),
+        }),
+        args.amount
+    )?;
+
+    sync_native(
+        CpiContext::new(order.token_program.to_account_info(), SyncNative {
+            account: order.user_from_ata.to_account_info(),
+        })
+    )?;
+
+    order.user_from_ata.reload()?;
+
+    open_order(
+        Context::new(ctx.program_id, order, ctx.remaining_accounts, ctx.bumps.order),
+        args
+    )?;
+
+    let order = &ctx.accounts.order;
+
+    // wSOL held before this instruction is left wrapped, closing would unwrap it too
+    if held_before > 0 {
+        return Ok(());
+    }
+
+    close_account(
+        CpiContext::new(order.token_program.to_account_info(), CloseAccount {
+            account: order.user_from_ata.to_account_info(),
+            destination: order.signer.to_account_info(),
+            authority: order.signer.to_account_info(),
+        })
+    )
+}
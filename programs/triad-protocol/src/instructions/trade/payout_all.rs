@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{ MarketV2, UserTrade, UserStats, Season, SeasonScore, OrderStatus, WinningDirection },
+    errors::TriadProtocolError,
+    events::OrderUpdate,
+    constraints::is_authority_for_user_trade,
+    utils::transfer_checked_with_hook,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PayoutAll<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = is_authority_for_user_trade(&user_trade, &signer)?
+    )]
+    pub user_trade: Box<Account<'info, UserTrade>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = UserStats::SPACE,
+        seeds = [UserStats::PREFIX_SEED, signer.key().as_ref(), market.mint.as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    /// Season joined by the signer, required while it runs, with their score
+    #[account(mut)]
+    pub season: Option<Box<Account<'info, Season>>>,
+
+    #[account(mut, constraint = season_score.authority == signer.key())]
+    pub season_score: Option<Box<Account<'info, SeasonScore>>>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn payout_all<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, PayoutAll<'info>>
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let user_trade = &mut ctx.accounts.user_trade;
+
+    require!(
+        market.winning_direction != WinningDirection::None,
+        TriadProtocolError::MarketNotResolved
+    );
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    let user_stats = &mut ctx.accounts.user_stats;
+
+    user_stats.init(ctx.bumps.user_stats, ctx.accounts.signer.key(), market.mint);
+
+    let mut total_payout = 0u64;
+    let mut paid_orders = 0u64;
+
+    for order_index in 0..user_trade.orders.len() {
+        let order = user_trade.orders[order_index];
+
+        if order.status != OrderStatus::Open || order.market_id != market.market_id {
+            continue;
+        }
+
+        let (payout, is_winner) = market.order_payout(&order)?;
+
+        total_payout = total_payout.checked_add(payout).unwrap();
+        paid_orders = paid_orders.checked_add(1).unwrap();
+        market.open_interest = market.open_interest.saturating_sub(order.total_amount);
+
+        user_trade.orders[order_index].status = OrderStatus::Closed;
+
+        let pnl = (payout as i64) - (order.total_amount as i64);
+
+        user_stats.record_close(0, pnl, ts);
+        user_stats.record_season_trade(
+            ctx.accounts.season.as_deref_mut(),
+            ctx.accounts.season_score.as_deref_mut(),
+            market.market_id,
+            0,
+            pnl,
+            ts
+        )?;
+
+        emit_cpi!(OrderUpdate {
+            user: *ctx.accounts.signer.key,
+            market_id: market.market_id,
+            seq: market.next_event_seq(),
+            order_id: order.order_id,
+            direction: order.direction,
+            order_type: order.order_type,
+            question_id: order.question_id,
+            order_status: OrderStatus::Closed,
+            price: order.price,
+            total_shares: order.total_shares,
+            total_amount: order.total_amount,
+            refund_amount: Some(payout),
+            timestamp: ts,
+            is_question_winner: Some(is_winner),
+            pnl,
+        });
+    }
+
+    require!(paid_orders > 0, TriadProtocolError::OrderNotFound);
+
+    if total_payout > 0 {
+        let signer: &[&[&[u8]]] = &[&[b"market", &market.market_id.to_le_bytes(), &[market.bump]]];
+
+        transfer_checked_with_hook(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.market_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.user_ata.to_account_info(),
+                    authority: market.to_account_info(),
+                },
+                signer
+            ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            total_payout,
+            ctx.accounts.mint.decimals
+        )?;
+
+        user_trade.total_withdraws = user_trade.total_withdraws.checked_add(total_payout).unwrap();
+        market.settled_claimed = market.settled_claimed.checked_add(total_payout).unwrap();
+    }
+
+    user_trade.opened_orders = user_trade.opened_orders.checked_sub(paid_orders).unwrap();
+
+    market.opened_orders = market.opened_orders.checked_sub(paid_orders).unwrap();
+
+    ctx.accounts.market_vault.reload()?;
+    market.check_solvency(ctx.accounts.market_vault.amount)?;
+
+    Ok(())
+}
@@ -1,14 +1,24 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{ Token2022, transfer_checked, TransferChecked };
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
 use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
 
 use crate::{
-    state::{ MarketV2, UserTrade, OrderStatus, OrderDirection, WinningDirection },
+    state::{
+        MarketV2,
+        UserTrade,
+        UserStats,
+        Season,
+        SeasonScore,
+        OrderStatus,
+        WinningDirection,
+    },
     errors::TriadProtocolError,
     events::OrderUpdate,
     constraints::is_authority_for_user_trade,
+    utils::transfer_checked_with_hook,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct PayoutOrder<'info> {
     #[account(mut)]
@@ -20,10 +30,26 @@ pub struct PayoutOrder<'info> {
     )]
     pub user_trade: Box<Account<'info, UserTrade>>,
 
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = UserStats::SPACE,
+        seeds = [UserStats::PREFIX_SEED, signer.key().as_ref(), market.mint.as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
     #[account(mut)]
     pub market: Box<Account<'info, MarketV2>>,
 
-    #[account(mut, constraint = mint.key() == market.mint)]
+    /// Season joined by the signer, required while it runs, with their score
+    #[account(mut)]
+    pub season: Option<Box<Account<'info, Season>>>,
+
+    #[account(mut, constraint = season_score.authority == signer.key())]
+    pub season_score: Option<Box<Account<'info, SeasonScore>>>,
+
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -43,12 +69,15 @@ pub struct PayoutOrder<'info> {
     )]
     pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn payout_order(ctx: Context<PayoutOrder>, order_id: u64) -> Result<()> {
+pub fn payout_order<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, PayoutOrder<'info>>,
+    order_id: u64
+) -> Result<()> {
     let market = &mut ctx.accounts.market;
     let user_trade = &mut ctx.accounts.user_trade;
 
@@ -71,49 +100,13 @@ pub fn payout_order(ctx: Context<PayoutOrder>, order_id: u64) -> Result<()> {
 
     require!(market.market_id == order.market_id, TriadProtocolError::OrderNotOpen);
 
-    let is_winner = match (order.direction, market.winning_direction) {
-        | (OrderDirection::Hype, WinningDirection::Hype)
-        | (OrderDirection::Flop, WinningDirection::Flop) => {
-            true
-        }
-        _ => { false }
-    };
-
-    let (market_shares, market_opposit_liquidity) = match market.winning_direction {
-        WinningDirection::Hype => (market.hype_shares, market.flop_liquidity),
-        WinningDirection::Flop => (market.flop_shares, market.hype_liquidity),
-        _ => (0, 0),
-    };
-
-    let market_initial_liquidity = if market.market_liquidity_at_start == 0 {
-        500_000_000
-    } else {
-        market.market_liquidity_at_start.checked_div(2).unwrap()
-    };
-
-    let mut payout = 0;
-    let market_liquidity = market_opposit_liquidity.checked_sub(market_initial_liquidity).unwrap();
-    let is_one_to_one = market_liquidity >= market_shares;
-
-    if is_winner && is_one_to_one {
-        payout = order.total_shares;
-    }
-
-    if is_winner && !is_one_to_one {
-        let shares_ratio = (order.total_shares as f64) / (market_shares as f64);
-        let additional_payout = (shares_ratio * (market_liquidity as f64)).round() as u64;
+    let (payout, is_winner) = market.order_payout(&order)?;
 
-        payout = additional_payout + order.total_amount;
-    }
-
-    if payout > order.total_shares {
-        payout = order.total_shares;
-    }
-
-    if payout > 0 && is_winner {
+    // Winners and the refunds of a voided market are both paid here
+    if payout > 0 {
         let signer: &[&[&[u8]]] = &[&[b"market", &market.market_id.to_le_bytes(), &[market.bump]]];
 
-        transfer_checked(
+        transfer_checked_with_hook(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 TransferChecked {
@@ -123,15 +116,14 @@ pub fn payout_order(ctx: Context<PayoutOrder>, order_id: u64) -> Result<()> {
                     authority: market.to_account_info(),
                 },
                 signer
-            ),
+            ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
             payout,
             ctx.accounts.mint.decimals
         )?;
 
         user_trade.total_withdraws = user_trade.total_withdraws.checked_add(payout).unwrap();
+        market.settled_claimed = market.settled_claimed.checked_add(payout).unwrap();
 
-        msg!("Market Shares {:?}", market_shares);
-        msg!("Market Liquidity {:?}", market_liquidity);
         msg!("Order Amount {:?}", order.total_amount);
         msg!("Order Shares {:?}", order.total_shares);
         msg!("Payout {:?}", payout);
@@ -141,10 +133,29 @@ pub fn payout_order(ctx: Context<PayoutOrder>, order_id: u64) -> Result<()> {
     user_trade.opened_orders = user_trade.opened_orders.checked_sub(1).unwrap();
 
     market.opened_orders = market.opened_orders.checked_sub(1).unwrap();
+    market.open_interest = market.open_interest.saturating_sub(order.total_amount);
+
+    let ts = Clock::get()?.unix_timestamp;
+    let pnl = (payout as i64) - (order.total_amount as i64);
 
-    emit!(OrderUpdate {
+    let user_stats = &mut ctx.accounts.user_stats;
+
+    user_stats.init(ctx.bumps.user_stats, ctx.accounts.signer.key(), market.mint);
+    user_stats.record_close(0, pnl, ts);
+
+    user_stats.record_season_trade(
+        ctx.accounts.season.as_deref_mut(),
+        ctx.accounts.season_score.as_deref_mut(),
+        market.market_id,
+        0,
+        pnl,
+        ts
+    )?;
+
+    emit_cpi!(OrderUpdate {
         user: *ctx.accounts.signer.key,
         market_id: market.market_id,
+        seq: market.next_event_seq(),
         order_id: order.order_id,
         direction: order.direction,
         order_type: order.order_type,
@@ -154,10 +165,13 @@ pub fn payout_order(ctx: Context<PayoutOrder>, order_id: u64) -> Result<()> {
         total_shares: order.total_shares,
         total_amount: order.total_amount,
         refund_amount: Some(payout),
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: ts,
         is_question_winner: Some(is_winner),
-        pnl: (payout as i64) - (order.total_amount as i64),
+        pnl,
     });
 
+    ctx.accounts.market_vault.reload()?;
+    market.check_solvency(ctx.accounts.market_vault.amount)?;
+
     Ok(())
 }
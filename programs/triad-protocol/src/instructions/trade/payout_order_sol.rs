@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{ close_account, CloseAccount };
+
+use crate::errors::TriadProtocolError;
+
+use super::payout_order::*;
+
+/// `payout_order` for SOL-denominated markets: the payout is unwrapped from the signer's wSOL
+/// account back to lamports. An account that already held wSOL is left open, the payout then
+/// stays wrapped in it alongside the existing balance.
+#[derive(Accounts)]
+pub struct PayoutOrderSol<'info> {
+    pub order: PayoutOrder<'info>,
+}
+
+pub fn payout_order_sol<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, PayoutOrderSol<'info>>,
+    order_id: u64
+) -> Result<()> {
+    require!(
+        ctx.accounts.order.mint.key() == native_mint::ID,
+        TriadProtocolError::NotNativeMarket
+    );
+
+    let held_before = ctx.accounts.order.user_ata.amount;
+
+    payout_order(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.order,
+            ctx.remaining_accounts,
+            ctx.bumps.order
+        ),
+        order_id
+    )?;
+
+    let order = &ctx.accounts.order;
+
+    // wSOL held before this instruction is left wrapped, closing would unwrap it too
+    if held_before > 0 {
+        return Ok(());
+    }
+
+    close_account(
+        CpiContext::new(order.token_program.to_account_info(), CloseAccount {
+            account: order.user_ata.to_account_info(),
+            destination: order.signer.to_account_info(),
+            authority: order.signer.to_account_info(),
+        })
+    )
+}
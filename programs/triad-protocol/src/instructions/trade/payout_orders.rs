@@ -0,0 +1,243 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{
+    associated_token::{ get_associated_token_address_with_program_id, AssociatedToken },
+    token_interface::{ Mint, TokenAccount },
+};
+
+use crate::{
+    state::{ MarketV2, UserTrade, UserStats, OrderStatus, WinningDirection },
+    errors::TriadProtocolError,
+    events::OrderUpdate,
+    constants::PAYOUT_KEEPER_FEE_BPS,
+    utils::transfer_checked_with_hook,
+};
+
+/// Permissionless crank paying out every open order of a resolved market for a batch of users.
+///
+/// `remaining_accounts` is a list of `(user_trade, user_ata, user_stats)` triples, where
+/// `user_ata` is the associated token account of the `user_trade` authority for the market mint
+/// and `user_stats` its `UserStats` for that mint, optionally followed by the extra accounts of
+/// the mint's transfer hook. Triples whose ATA is not initialised or is frozen, or whose owner
+/// is in a running season and must score the payout there, are skipped and their orders left
+/// open.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PayoutOrders<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub keeper_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn payout_orders<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, PayoutOrders<'info>>
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    require!(
+        market.winning_direction != WinningDirection::None,
+        TriadProtocolError::MarketNotResolved
+    );
+
+    // Triples lead with a program-owned `user_trade`, anything after them belongs to the hook
+    let triples_len =
+        ctx.remaining_accounts
+            .chunks_exact(3)
+            .take_while(|accounts| accounts[0].owner == &crate::ID)
+            .count() * 3;
+    let (order_accounts, hook_accounts) = ctx.remaining_accounts.split_at(triples_len);
+
+    require!(!order_accounts.is_empty(), TriadProtocolError::InvalidRemainingAccounts);
+
+    let batch = order_accounts.chunks_exact(3);
+
+    let ts = Clock::get()?.unix_timestamp;
+    let market_seeds: &[&[&[u8]]] = &[
+        &[b"market", &market.market_id.to_le_bytes(), &[market.bump]],
+    ];
+
+    let mut keeper_fee = 0u64;
+
+    for accounts in batch {
+        let mut user_trade = Account::<UserTrade>::try_from(&accounts[0])?;
+        let user_ata_info = &accounts[1];
+
+        require!(
+            user_ata_info.key() ==
+                get_associated_token_address_with_program_id(
+                    &user_trade.authority,
+                    &market.mint,
+                    &ctx.accounts.token_program.key()
+                ),
+            TriadProtocolError::InvalidRemainingAccounts
+        );
+
+        // A missing or frozen ATA must not revert the batch, its orders stay open for later
+        let is_payable = user_ata_info.owner == &ctx.accounts.token_program.key() &&
+            InterfaceAccount::<TokenAccount>
+                ::try_from(user_ata_info)
+                .map(|user_ata| user_ata.mint == market.mint && !user_ata.is_frozen())
+                .unwrap_or(false);
+
+        if !is_payable {
+            continue;
+        }
+
+        // Stats are created by the owner's first trade in this mint, none to update before that
+        let user_stats_info = &accounts[2];
+        let mut user_stats = if user_stats_info.owner == &crate::ID {
+            let user_stats = Account::<UserStats>::try_from(user_stats_info)?;
+
+            require!(
+                user_stats.authority == user_trade.authority && user_stats.mint == market.mint,
+                TriadProtocolError::InvalidRemainingAccounts
+            );
+
+            if user_stats.in_season(ts) {
+                continue;
+            }
+
+            Some(user_stats)
+        } else {
+            let (user_stats_key, _) = Pubkey::find_program_address(
+                &[UserStats::PREFIX_SEED, user_trade.authority.as_ref(), market.mint.as_ref()],
+                &crate::ID
+            );
+
+            require!(
+                user_stats_info.key() == user_stats_key,
+                TriadProtocolError::InvalidRemainingAccounts
+            );
+
+            None
+        };
+
+        let mut user_payout = 0u64;
+
+        for order_index in 0..user_trade.orders.len() {
+            let order = user_trade.orders[order_index];
+
+            if order.status != OrderStatus::Open || order.market_id != market.market_id {
+                continue;
+            }
+
+            let (payout, is_winner) = market.order_payout(&order)?;
+
+            user_payout = user_payout.checked_add(payout).unwrap();
+
+            user_trade.orders[order_index].status = OrderStatus::Closed;
+            user_trade.opened_orders = user_trade.opened_orders.checked_sub(1).unwrap();
+
+            market.opened_orders = market.opened_orders.checked_sub(1).unwrap();
+            market.open_interest = market.open_interest.saturating_sub(order.total_amount);
+
+            let pnl = (payout as i64) - (order.total_amount as i64);
+
+            if let Some(user_stats) = &mut user_stats {
+                user_stats.record_close(0, pnl, ts);
+            }
+
+            emit_cpi!(OrderUpdate {
+                user: user_trade.authority,
+                market_id: market.market_id,
+                seq: market.next_event_seq(),
+                order_id: order.order_id,
+                direction: order.direction,
+                order_type: order.order_type,
+                question_id: order.question_id,
+                order_status: OrderStatus::Closed,
+                price: order.price,
+                total_shares: order.total_shares,
+                total_amount: order.total_amount,
+                refund_amount: Some(payout),
+                timestamp: ts,
+                is_question_winner: Some(is_winner),
+                pnl,
+            });
+        }
+
+        if user_payout > 0 {
+            let user_keeper_fee = user_payout
+                .checked_mul(PAYOUT_KEEPER_FEE_BPS)
+                .unwrap()
+                .checked_div(10000)
+                .unwrap();
+            let net_payout = user_payout.checked_sub(user_keeper_fee).unwrap();
+
+            transfer_checked_with_hook(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.market_vault.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        to: user_ata_info.to_account_info(),
+                        authority: market.to_account_info(),
+                    },
+                    market_seeds
+                ).with_remaining_accounts(hook_accounts.to_vec()),
+                net_payout,
+                ctx.accounts.mint.decimals
+            )?;
+
+            user_trade.total_withdraws = user_trade.total_withdraws
+                .checked_add(net_payout)
+                .unwrap();
+            keeper_fee = keeper_fee.checked_add(user_keeper_fee).unwrap();
+            market.settled_claimed = market.settled_claimed.checked_add(user_payout).unwrap();
+        }
+
+        user_trade.exit(&crate::ID)?;
+
+        if let Some(user_stats) = &user_stats {
+            user_stats.exit(&crate::ID)?;
+        }
+    }
+
+    if keeper_fee > 0 {
+        transfer_checked_with_hook(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.market_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.keeper_ata.to_account_info(),
+                    authority: market.to_account_info(),
+                },
+                market_seeds
+            ).with_remaining_accounts(hook_accounts.to_vec()),
+            keeper_fee,
+            ctx.accounts.mint.decimals
+        )?;
+    }
+
+    ctx.accounts.market_vault.reload()?;
+    market.check_solvency(ctx.accounts.market_vault.amount)?;
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{ MarketV2, VotingMarket, PromoteVotingMarketArgs },
+    errors::TriadProtocolError,
+    events::{ MarketUpdate, VotingMarketUpdate },
+    constraints::is_admin,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: PromoteVotingMarketArgs)]
+pub struct PromoteVotingMarket<'info> {
+    #[account(mut, constraint = is_admin(&signer)?)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub voting_market: Box<Account<'info, VotingMarket>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = MarketV2::SPACE,
+        seeds = [MarketV2::PREFIX_SEED, &args.market_id.to_le_bytes()],
+        bump
+    )]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == voting_market.mint,
+        mint::token_program = token_program
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program
+    )]
+    pub market_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn promote_voting_market(
+    ctx: Context<PromoteVotingMarket>,
+    args: PromoteVotingMarketArgs
+) -> Result<()> {
+    let voting_market = &mut ctx.accounts.voting_market;
+    let market = &mut ctx.accounts.market;
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    require!(!voting_market.is_promoted, TriadProtocolError::VotingMarketAlreadyPromoted);
+    require!(ts > voting_market.voting_end, TriadProtocolError::VotingStillActive);
+    require!(ts < voting_market.market_end, TriadProtocolError::QuestionPeriodEnded);
+    require!(
+        voting_market.total_votes() >= voting_market.min_votes,
+        TriadProtocolError::NotEnoughVotes
+    );
+
+    market.set_inner(MarketV2 {
+        bump: ctx.bumps.market,
+        authority: ctx.accounts.signer.key(),
+        market_id: args.market_id,
+        mint: ctx.accounts.mint.key(),
+        market_start: voting_market.market_start,
+        market_end: voting_market.market_end,
+        question: voting_market.question,
+        update_ts: ts,
+        ..Default::default()
+    });
+
+    market.set_collateral_decimals(ctx.accounts.mint.decimals)?;
+
+    voting_market.market_id = args.market_id;
+    voting_market.is_promoted = true;
+
+    emit_cpi!(MarketUpdate {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        question: String::from_utf8_lossy(&market.question).to_string(),
+        start_time: market.market_start,
+        end_time: market.market_end,
+        hype_liquidity: market.hype_liquidity,
+        flop_liquidity: market.flop_liquidity,
+        winning_direction: market.winning_direction,
+        final_hype_price: market.hype_price,
+        final_flop_price: market.flop_price,
+        timestamp: ts,
+        total_hype_shares: market.hype_shares,
+        total_flop_shares: market.flop_shares,
+        payout_per_share: market.payout_per_share,
+    });
+
+    emit_cpi!(VotingMarketUpdate {
+        voting_market: voting_market.key(),
+        vote_id: voting_market.vote_id,
+        authority: voting_market.authority,
+        question: String::from_utf8_lossy(&voting_market.question).to_string(),
+        market_id: voting_market.market_id,
+        voting_end: voting_market.voting_end,
+        market_start: voting_market.market_start,
+        market_end: voting_market.market_end,
+        total_hype: voting_market.total_hype,
+        total_flop: voting_market.total_flop,
+        is_promoted: voting_market.is_promoted,
+        timestamp: ts,
+    });
+
+    Ok(())
+}
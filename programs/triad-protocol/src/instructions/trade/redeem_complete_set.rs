@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{
+        MarketV2,
+        UserTrade,
+        Order,
+        OrderDirection,
+        OrderStatus,
+        RedeemCompleteSetArgs,
+        WinningDirection,
+    },
+    errors::TriadProtocolError,
+    events::OrderUpdate,
+    constraints::is_authority_for_user_trade,
+    utils::transfer_checked_with_hook,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RedeemCompleteSet<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = is_authority_for_user_trade(&user_trade, &signer)?
+    )]
+    pub user_trade: Box<Account<'info, UserTrade>>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn redeem_complete_set<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RedeemCompleteSet<'info>>,
+    args: RedeemCompleteSetArgs
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let user_trade = &mut ctx.accounts.user_trade;
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    require!(market.is_active, TriadProtocolError::MarketInactive);
+    require!(
+        market.winning_direction == WinningDirection::None,
+        TriadProtocolError::MarketAlreadyResolved
+    );
+    require!(args.shares > 0, TriadProtocolError::InsufficientShares);
+
+    let find_order = |order_id: u64| {
+        user_trade.orders
+            .iter()
+            .position(
+                |order|
+                    order.order_id == order_id &&
+                    order.status == OrderStatus::Open &&
+                    order.market_id == market.market_id
+            )
+            .ok_or(TriadProtocolError::OrderNotFound)
+    };
+
+    let hype_index = find_order(args.hype_order_id)?;
+    let flop_index = find_order(args.flop_order_id)?;
+
+    for (order_index, direction) in [
+        (hype_index, OrderDirection::Hype),
+        (flop_index, OrderDirection::Flop),
+    ] {
+        let order = user_trade.orders[order_index];
+
+        require!(order.direction == direction, TriadProtocolError::InvalidOrderDirection);
+        require!(order.total_shares >= args.shares, TriadProtocolError::InsufficientShares);
+    }
+
+    // Cost basis released in proportion to the shares burned from each order. Each side gives
+    // back exactly what the order put in, so mint/redeem round trips leave the pool unchanged.
+    let released = |order_index: usize| -> Result<u64> {
+        let order = user_trade.orders[order_index];
+
+        ((order.total_amount as u128) * (args.shares as u128))
+            .checked_div(order.total_shares as u128)
+            .unwrap()
+            .try_into()
+            .map_err(|_| TriadProtocolError::ArithmeticOverflow.into())
+    };
+
+    let hype_released = released(hype_index)?;
+    let flop_released = released(flop_index)?;
+
+    require!(market.hype_liquidity > hype_released, TriadProtocolError::InsufficientLiquidity);
+    require!(market.flop_liquidity > flop_released, TriadProtocolError::InsufficientLiquidity);
+
+    // The burned pair is worth exactly `shares`, split across the legs for reporting
+    let hype_refund = hype_released.min(args.shares);
+    let flop_refund = args.shares.checked_sub(hype_refund).unwrap();
+
+    let legs = [
+        (hype_index, hype_released, hype_refund),
+        (flop_index, flop_released, flop_refund),
+    ];
+
+    let signer: &[&[&[u8]]] = &[&[b"market", &market.market_id.to_le_bytes(), &[market.bump]]];
+
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.market_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.user_ata.to_account_info(),
+                authority: market.to_account_info(),
+            },
+            signer
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        args.shares,
+        ctx.accounts.mint.decimals
+    )?;
+
+    market.hype_liquidity = market.hype_liquidity.checked_sub(hype_released).unwrap();
+    market.flop_liquidity = market.flop_liquidity.checked_sub(flop_released).unwrap();
+    market.hype_shares = market.hype_shares.checked_sub(args.shares).unwrap();
+    market.flop_shares = market.flop_shares.checked_sub(args.shares).unwrap();
+
+    user_trade.total_withdraws = user_trade.total_withdraws.checked_add(args.shares).unwrap();
+
+    // Sets are fee free, so they are left out of the user stats and season scores rather than
+    // letting mint/redeem loops farm volume
+    for (order_index, released_amount, refund_amount) in legs {
+        let order = user_trade.orders[order_index];
+
+        market.open_interest = market.open_interest.saturating_sub(released_amount);
+
+        let order_status = if order.total_shares == args.shares {
+            user_trade.orders[order_index] = Order::default();
+            user_trade.opened_orders = user_trade.opened_orders.checked_sub(1).unwrap();
+            market.opened_orders = market.opened_orders.checked_sub(1).unwrap();
+
+            OrderStatus::Closed
+        } else {
+            let current_order = &mut user_trade.orders[order_index];
+            current_order.total_shares = current_order.total_shares
+                .checked_sub(args.shares)
+                .unwrap();
+            current_order.total_amount = current_order.total_amount
+                .checked_sub(released_amount)
+                .unwrap();
+
+            OrderStatus::Open
+        };
+
+        let pnl = (refund_amount as i64) - (released_amount as i64);
+
+        emit_cpi!(OrderUpdate {
+            user: *ctx.accounts.signer.key,
+            market_id: market.market_id,
+            seq: market.next_event_seq(),
+            order_id: order.order_id,
+            direction: order.direction,
+            order_type: order.order_type,
+            question_id: order.question_id,
+            order_status,
+            price: order.price,
+            total_shares: args.shares,
+            total_amount: released_amount,
+            refund_amount: Some(refund_amount),
+            timestamp: ts,
+            is_question_winner: None,
+            pnl,
+        });
+    }
+
+    ctx.accounts.market_vault.reload()?;
+    market.check_solvency(ctx.accounts.market_vault.amount)?;
+
+    Ok(())
+}
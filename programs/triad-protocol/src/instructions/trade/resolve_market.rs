@@ -1,21 +1,31 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constraints::is_admin,
+    constraints::{ is_admin, is_resolver_for_market },
     errors::TriadProtocolError,
     events::MarketUpdate,
+    BatchAuction,
+    MarketBond,
     MarketV2,
     WinningDirection,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ResolveMarket<'info> {
-    #[account(mut, constraint = is_admin(&signer)?)]
+    #[account(mut)]
     pub signer: Signer<'info>,
 
     #[account(mut)]
     pub market: Box<Account<'info, MarketV2>>,
 
+    /// Only required when the resolver named by the market creator signs
+    pub market_bond: Option<Box<Account<'info, MarketBond>>>,
+
+    /// Required for batch auction markets, which settle only once their last batch is cleared
+    #[account(has_one = market)]
+    pub batch_auction: Option<Box<Account<'info, BatchAuction>>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -26,6 +36,23 @@ pub fn resolve_market(
     let market = &mut ctx.accounts.market;
     let current_timestamp = Clock::get()?.unix_timestamp;
 
+    if !is_admin(&ctx.accounts.signer)? {
+        let market_bond = ctx.accounts.market_bond
+            .as_ref()
+            .ok_or(TriadProtocolError::Unauthorized)?;
+
+        require!(
+            is_resolver_for_market(market_bond, &market.key(), &ctx.accounts.signer)?,
+            TriadProtocolError::Unauthorized
+        );
+        require!(current_timestamp > market.market_end, TriadProtocolError::MarketStillActive);
+    }
+
+    require!(
+        matches!(winning_direction, WinningDirection::Hype | WinningDirection::Flop),
+        TriadProtocolError::InvalidWinningDirection
+    );
+
     market.is_active = false;
 
     require!(
@@ -33,10 +60,14 @@ pub fn resolve_market(
         TriadProtocolError::MarketAlreadyResolved
     );
 
+    BatchAuction::check_cleared(market, ctx.accounts.batch_auction.as_deref().map(|b| &**b))?;
+
     market.winning_direction = winning_direction;
+    market.snapshot_settlement()?;
 
-    emit!(MarketUpdate {
+    emit_cpi!(MarketUpdate {
         market_id: market.market_id,
+        seq: market.next_event_seq(),
         question: String::from_utf8_lossy(&market.question).to_string(),
         start_time: market.market_start,
         end_time: market.market_end,
@@ -48,6 +79,7 @@ pub fn resolve_market(
         timestamp: current_timestamp,
         total_hype_shares: market.hype_shares,
         total_flop_shares: market.flop_shares,
+        payout_per_share: market.payout_per_share,
     });
 
     Ok(())
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ MarketV2, UserTrade, OrderStatus, SetOrderTriggersArgs },
+    errors::TriadProtocolError,
+    events::OrderTriggersUpdate,
+    constraints::is_authority_for_user_trade,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetOrderTriggers<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = is_authority_for_user_trade(&user_trade, &signer)?
+    )]
+    pub user_trade: Box<Account<'info, UserTrade>>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, MarketV2>>,
+}
+
+pub fn set_order_triggers(
+    ctx: Context<SetOrderTriggers>,
+    args: SetOrderTriggersArgs
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let user_trade = &mut ctx.accounts.user_trade;
+
+    let order_index = user_trade.orders
+        .iter()
+        .position(
+            |order|
+                order.order_id == args.order_id &&
+                order.market_id == market.market_id &&
+                order.status == OrderStatus::Open
+        )
+        .ok_or(TriadProtocolError::OrderNotFound)?;
+
+    let current_price = market.price(user_trade.orders[order_index].direction);
+
+    // Triggers must sit on the right side of the current price so they don't fire immediately
+    require!(
+        args.stop_loss_price < current_price &&
+            (args.take_profit_price == 0 ||
+                (args.take_profit_price > current_price && args.take_profit_price < 1_000_000)),
+        TriadProtocolError::InvalidOrderTrigger
+    );
+
+    let order = &mut user_trade.orders[order_index];
+
+    order.stop_loss_price = args.stop_loss_price;
+    order.take_profit_price = args.take_profit_price;
+
+    emit_cpi!(OrderTriggersUpdate {
+        user: *ctx.accounts.signer.key,
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        order_id: args.order_id,
+        stop_loss_price: args.stop_loss_price,
+        take_profit_price: args.take_profit_price,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
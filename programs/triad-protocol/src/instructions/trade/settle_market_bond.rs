@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{ MarketV2, MarketBond, BondStatus, WinningDirection },
+    errors::TriadProtocolError,
+    events::MarketBondUpdate,
+    constants::ADMIN,
+    utils::transfer_checked_with_hook,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleMarketBond<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(
+        mut,
+        seeds = [MarketBond::PREFIX_SEED, market.key().as_ref()],
+        bump = market_bond.bump
+    )]
+    pub market_bond: Box<Account<'info, MarketBond>>,
+
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = market_bond,
+        associated_token::token_program = token_program
+    )]
+    pub bond_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Market creator
+    #[account(constraint = creator.key() == market_bond.creator)]
+    pub creator: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program
+    )]
+    pub creator_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Admin Account
+    #[account(constraint = admin.key.to_string() == ADMIN)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = admin,
+        associated_token::token_program = token_program
+    )]
+    pub admin_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Returns the bond to the creator once the market is resolved, or slashes it to the protocol if
+/// the market was voided. What is left of the seed liquidity goes back to the creator either way.
+pub fn settle_market_bond<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SettleMarketBond<'info>>
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let market_bond = &mut ctx.accounts.market_bond;
+
+    require!(market_bond.status == BondStatus::Posted, TriadProtocolError::BondAlreadySettled);
+    require!(
+        market.winning_direction != WinningDirection::None,
+        TriadProtocolError::MarketNotResolved
+    );
+
+    let is_void = market.winning_direction == WinningDirection::Void;
+
+    let bond_signer: &[&[&[u8]]] = &[
+        &[MarketBond::PREFIX_SEED, market_bond.market.as_ref(), &[market_bond.bump]],
+    ];
+
+    let bond_to = if is_void {
+        ctx.accounts.admin_ata.to_account_info()
+    } else {
+        ctx.accounts.creator_ata.to_account_info()
+    };
+
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.bond_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: bond_to,
+                authority: market_bond.to_account_info(),
+            },
+            bond_signer
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        market_bond.amount,
+        ctx.accounts.mint.decimals
+    )?;
+
+    // Only the surplus over what the market still owes can go back to the creator
+    let report = market.solvency_report(ctx.accounts.market_vault.amount);
+    let surplus = report.vault_balance
+        .saturating_sub(report.outstanding_liabilities)
+        .saturating_sub(report.unclaimed_fees);
+    let seed_refund = surplus.min(market_bond.seed_liquidity);
+
+    if seed_refund > 0 {
+        let market_signer: &[&[&[u8]]] = &[
+            &[b"market", &market.market_id.to_le_bytes(), &[market.bump]],
+        ];
+
+        transfer_checked_with_hook(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.market_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.creator_ata.to_account_info(),
+                    authority: market.to_account_info(),
+                },
+                market_signer
+            ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            seed_refund,
+            ctx.accounts.mint.decimals
+        )?;
+    }
+
+    market_bond.status = if is_void { BondStatus::Slashed } else { BondStatus::Returned };
+
+    emit_cpi!(MarketBondUpdate {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        creator: market_bond.creator,
+        resolver: market_bond.resolver,
+        amount: market_bond.amount,
+        status: market_bond.status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    ctx.accounts.market_vault.reload()?;
+    market.check_solvency(ctx.accounts.market_vault.amount)?;
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
+use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
+
+use crate::{
+    state::{
+        MarketV2,
+        MarketMode,
+        BatchAuction,
+        UserTrade,
+        UserStats,
+        Season,
+        SeasonScore,
+        Order,
+        OrderStatus,
+        OrderType,
+        SubmitBatchOrderArgs,
+    },
+    errors::TriadProtocolError,
+    events::OrderUpdate,
+    constraints::is_authority_for_user_trade,
+    utils::{ get_transfer_fee, transfer_checked_with_hook },
+};
+
+/// Queues an order in the current batch of a `MarketMode::BatchAuction` market. The order
+/// stays `Pending` until `clear_batch` fills it at the batch clearing price.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SubmitBatchOrder<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = is_authority_for_user_trade(&user_trade, &signer)?
+    )]
+    pub user_trade: Box<Account<'info, UserTrade>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = UserStats::SPACE,
+        seeds = [UserStats::PREFIX_SEED, signer.key().as_ref(), market.mint.as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    /// Season joined by the signer, required while it runs, with their score
+    #[account(mut)]
+    pub season: Option<Box<Account<'info, Season>>>,
+
+    #[account(mut, constraint = season_score.authority == signer.key())]
+    pub season_score: Option<Box<Account<'info, SeasonScore>>>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(mut, has_one = market)]
+    pub batch_auction: Box<Account<'info, BatchAuction>>,
+
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_from_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program
+    )]
+    pub market_to_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn submit_batch_order<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SubmitBatchOrder<'info>>,
+    args: SubmitBatchOrderArgs
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let batch_auction = &mut ctx.accounts.batch_auction;
+    let user_trade = &mut ctx.accounts.user_trade;
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    require!(market.is_active, TriadProtocolError::MarketInactive);
+    require!(market.mode == MarketMode::BatchAuction, TriadProtocolError::InvalidMarketMode);
+    require!(ts > market.market_start, TriadProtocolError::QuestionPeriodNotStarted);
+    require!(market.market_end > ts, TriadProtocolError::QuestionPeriodEnded);
+    require!(
+        ctx.accounts.user_from_ata.amount >= args.amount,
+        TriadProtocolError::InsufficientFunds
+    );
+
+    batch_auction.roll_window(ts, market.market_end);
+
+    require!(
+        !batch_auction.is_clearing && ts < batch_auction.batch_end,
+        TriadProtocolError::BatchClosed
+    );
+
+    let transfer_fee = get_transfer_fee(&ctx.accounts.mint.to_account_info(), args.amount)?;
+    let received_amount = args.amount.checked_sub(transfer_fee).unwrap();
+
+    let fee_amount = (received_amount * (market.fee_bps as u64)) / 100000;
+    let net_amount = received_amount.saturating_sub(fee_amount);
+
+    require!(net_amount > 0, TriadProtocolError::InsufficientFunds);
+
+    market.check_order_size(net_amount)?;
+    market.check_position_limits(user_trade.market_stake(market.market_id), net_amount)?;
+
+    let is_new_market = user_trade.market_stake(market.market_id) == 0;
+
+    let order_index = user_trade.orders
+        .iter()
+        .position(|order| !order.is_active())
+        .ok_or(TriadProtocolError::NoAvailableOrderSlot)?;
+
+    let order = Order {
+        ts,
+        order_id: market.next_order_id(),
+        question_id: 0,
+        market_id: market.market_id,
+        status: OrderStatus::Pending,
+        price: 0,
+        total_amount: net_amount,
+        total_shares: 0,
+        order_type: OrderType::Market,
+        direction: args.direction,
+        stop_loss_price: 0,
+        take_profit_price: 0,
+        batch_id: batch_auction.batch_id,
+        padding: [0; 8],
+    };
+
+    user_trade.orders[order_index] = order;
+    user_trade.opened_orders = user_trade.opened_orders.checked_add(1).unwrap();
+    user_trade.total_deposits = user_trade.total_deposits.checked_add(net_amount).unwrap();
+
+    batch_auction.add_order(args.direction, net_amount);
+
+    // The collateral is committed here, `clear_batch` only fixes the price it fills at
+    let user_stats = &mut ctx.accounts.user_stats;
+
+    user_stats.init(ctx.bumps.user_stats, ctx.accounts.signer.key(), market.mint);
+    user_stats.record_open(net_amount, fee_amount, is_new_market, ts);
+    user_stats.record_season_trade(
+        ctx.accounts.season.as_deref_mut(),
+        ctx.accounts.season_score.as_deref_mut(),
+        market.market_id,
+        net_amount,
+        0,
+        ts
+    )?;
+
+    market.opened_orders = market.opened_orders.checked_add(1).unwrap();
+    market.volume = market.volume.checked_add(net_amount).unwrap();
+    market.open_interest = market.open_interest.checked_add(net_amount).unwrap();
+
+    let nft_holders_fee = (fee_amount * 100) / 10000; // 0.1%
+    let market_fee = fee_amount - nft_holders_fee;
+    let creator_fee = (market_fee * (market.creator_fee_bps as u64)) / 10000;
+
+    market.nft_holders_fee_available = market.nft_holders_fee_available
+        .checked_add(nft_holders_fee)
+        .unwrap();
+    market.market_fee_available = market.market_fee_available
+        .checked_add(market_fee - creator_fee)
+        .unwrap();
+    market.market_maker_fee_available = market.market_maker_fee_available
+        .checked_add(creator_fee)
+        .unwrap();
+
+    transfer_checked_with_hook(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), TransferChecked {
+            from: ctx.accounts.user_from_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.market_to_ata.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+        }).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        args.amount,
+        ctx.accounts.mint.decimals
+    )?;
+
+    emit_cpi!(OrderUpdate {
+        user: *ctx.accounts.signer.key,
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        order_id: order.order_id,
+        direction: order.direction,
+        order_type: order.order_type,
+        question_id: order.question_id,
+        order_status: OrderStatus::Pending,
+        price: 0,
+        total_shares: 0,
+        total_amount: net_amount,
+        refund_amount: None,
+        timestamp: ts,
+        is_question_winner: None,
+        pnl: 0,
+    });
+
+    ctx.accounts.market_to_ata.reload()?;
+    market.check_solvency(ctx.accounts.market_to_ata.amount)?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ MarketV2, UpdateMarketLimitsArgs },
+    errors::TriadProtocolError,
+    events::MarketLimitsUpdate,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMarketLimits<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, constraint = market.authority == signer.key())]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_market_limits(
+    ctx: Context<UpdateMarketLimits>,
+    args: UpdateMarketLimitsArgs
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    if let Some(max_user_stake) = args.max_user_stake {
+        market.max_user_stake = max_user_stake;
+    }
+
+    if let Some(max_order_liquidity_bps) = args.max_order_liquidity_bps {
+        market.max_order_liquidity_bps = max_order_liquidity_bps;
+    }
+
+    if let Some(max_open_interest) = args.max_open_interest {
+        market.max_open_interest = max_open_interest;
+    }
+
+    if let Some(referral_fee_bps) = args.referral_fee_bps {
+        require!(referral_fee_bps <= 10000, TriadProtocolError::InvalidMarketParameters);
+
+        market.referral_fee_bps = referral_fee_bps;
+    }
+
+    emit_cpi!(MarketLimitsUpdate {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        max_user_stake: market.max_user_stake,
+        max_order_liquidity_bps: market.max_order_liquidity_bps,
+        max_open_interest: market.max_open_interest,
+        referral_fee_bps: market.referral_fee_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ MarketV2, MarketBond, MarketMetadata, MarketMetadataArgs },
+    errors::TriadProtocolError,
+    events::MarketMetadataUpdate,
+    constraints::is_creator_for_market,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: MarketMetadataArgs)]
+pub struct UpdateMarketMetadata<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    /// Only required when the market creator signs
+    pub market_bond: Option<Box<Account<'info, MarketBond>>>,
+
+    #[account(
+        mut,
+        has_one = market,
+        realloc = MarketMetadata::space(&args),
+        realloc::payer = signer,
+        realloc::zero = false
+    )]
+    pub market_metadata: Box<Account<'info, MarketMetadata>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_market_metadata(
+    ctx: Context<UpdateMarketMetadata>,
+    args: MarketMetadataArgs
+) -> Result<()> {
+    // Permissionless markets are owned by the admin, their creator edits the metadata
+    if ctx.accounts.market.authority != ctx.accounts.signer.key() {
+        let market_bond = ctx.accounts.market_bond
+            .as_ref()
+            .ok_or(TriadProtocolError::Unauthorized)?;
+
+        require!(
+            is_creator_for_market(market_bond, &ctx.accounts.market.key(), &ctx.accounts.signer)?,
+            TriadProtocolError::Unauthorized
+        );
+    }
+
+    let ts = Clock::get()?.unix_timestamp;
+
+    // Traders rely on the question and rules once the market is open
+    require!(ts < ctx.accounts.market.market_start, TriadProtocolError::MarketAlreadyStarted);
+
+    MarketMetadata::validate(&args)?;
+
+    ctx.accounts.market_metadata.set_content(args, ts);
+
+    let market = &mut ctx.accounts.market;
+    let market_metadata = &ctx.accounts.market_metadata;
+
+    emit_cpi!(MarketMetadataUpdate {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        market_metadata: market_metadata.key(),
+        question: market_metadata.question.clone(),
+        category: market_metadata.category.clone(),
+        image: market_metadata.image.clone(),
+        timestamp: ts,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{ Token2022, transfer_checked, TransferChecked };
+use anchor_spl::token_interface::{ TokenInterface, TransferChecked };
 use anchor_spl::{ associated_token::AssociatedToken, token_interface::{ Mint, TokenAccount } };
 
 use crate::{
@@ -7,8 +7,10 @@ use crate::{
     errors::TriadProtocolError,
     events::OrderUpdate,
     constraints::is_authority_for_user_trade,
+    utils::transfer_checked_with_hook,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SettleOrder<'info> {
     #[account(mut)]
@@ -23,7 +25,7 @@ pub struct SettleOrder<'info> {
     #[account(mut)]
     pub market: Box<Account<'info, Market>>,
 
-    #[account(mut, constraint = mint.key() == market.mint)]
+    #[account(mut, constraint = mint.key() == market.mint, mint::token_program = token_program)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -43,12 +45,15 @@ pub struct SettleOrder<'info> {
     )]
     pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn settle_order(ctx: Context<SettleOrder>, order_id: u64) -> Result<()> {
+pub fn settle_order<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SettleOrder<'info>>,
+    order_id: u64
+) -> Result<()> {
     let market = &mut ctx.accounts.market;
     let user_trade = &mut ctx.accounts.user_trade;
 
@@ -103,7 +108,7 @@ pub fn settle_order(ctx: Context<SettleOrder>, order_id: u64) -> Result<()> {
     if payout > 0 && is_winner {
         let signer: &[&[&[u8]]] = &[&[b"market", &market.market_id.to_le_bytes(), &[market.bump]]];
 
-        transfer_checked(
+        transfer_checked_with_hook(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 TransferChecked {
@@ -113,7 +118,7 @@ pub fn settle_order(ctx: Context<SettleOrder>, order_id: u64) -> Result<()> {
                     authority: market.to_account_info(),
                 },
                 signer
-            ),
+            ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
             payout,
             ctx.accounts.mint.decimals
         )?;
@@ -128,9 +133,10 @@ pub fn settle_order(ctx: Context<SettleOrder>, order_id: u64) -> Result<()> {
 
     market.open_orders_count = market.open_orders_count.checked_sub(1).unwrap();
 
-    emit!(OrderUpdate {
+    emit_cpi!(OrderUpdate {
         user: *ctx.accounts.signer.key,
         market_id: market.market_id,
+        seq: market.next_event_seq(),
         order_id: order.order_id,
         direction: order.direction,
         order_type: order.order_type,
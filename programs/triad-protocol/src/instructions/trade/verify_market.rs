@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenInterface;
+use anchor_spl::token_interface::{ Mint, TokenAccount };
+
+use crate::{ state::{ MarketV2, SolvencyReport }, events::SolvencyUpdate };
+
+#[derive(Accounts)]
+pub struct VerifyMarket<'info> {
+    pub signer: Signer<'info>,
+
+    pub market: Box<Account<'info, MarketV2>>,
+
+    #[account(constraint = mint.key() == market.mint, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn verify_market(ctx: Context<VerifyMarket>) -> Result<SolvencyReport> {
+    let market = &ctx.accounts.market;
+
+    let report = market.solvency_report(ctx.accounts.market_vault.amount);
+
+    // Left out of the event sequence, verifying must not write-lock the market
+    emit!(SolvencyUpdate {
+        market_id: report.market_id,
+        vault_balance: report.vault_balance,
+        outstanding_liabilities: report.outstanding_liabilities,
+        unclaimed_fees: report.unclaimed_fees,
+        is_solvent: report.is_solvent,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(report)
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constraints::is_admin,
+    errors::TriadProtocolError,
+    events::MarketUpdate,
+    BatchAuction,
+    MarketV2,
+    WinningDirection,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VoidMarket<'info> {
+    #[account(mut, constraint = is_admin(&signer)?)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, MarketV2>>,
+
+    /// Required for batch auction markets, which settle only once their last batch is cleared
+    #[account(has_one = market)]
+    pub batch_auction: Option<Box<Account<'info, BatchAuction>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Cancels a malformed market, open orders are refunded their cost basis from the pool
+pub fn void_market(ctx: Context<VoidMarket>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+
+    require!(
+        market.winning_direction == WinningDirection::None,
+        TriadProtocolError::MarketAlreadyResolved
+    );
+    // Orders opened before open interest was tracked have no recorded cost basis to refund
+    require!(
+        market.open_interest > 0 || market.opened_orders == 0,
+        TriadProtocolError::OpenInterestNotRecorded
+    );
+
+    BatchAuction::check_cleared(market, ctx.accounts.batch_auction.as_deref().map(|b| &**b))?;
+
+    market.is_active = false;
+    market.winning_direction = WinningDirection::Void;
+    market.snapshot_settlement()?;
+
+    emit_cpi!(MarketUpdate {
+        market_id: market.market_id,
+        seq: market.next_event_seq(),
+        question: String::from_utf8_lossy(&market.question).to_string(),
+        start_time: market.market_start,
+        end_time: market.market_end,
+        hype_liquidity: market.hype_liquidity,
+        flop_liquidity: market.flop_liquidity,
+        winning_direction: market.winning_direction,
+        final_hype_price: market.hype_price,
+        final_flop_price: market.flop_price,
+        timestamp: current_timestamp,
+        total_hype_shares: market.hype_shares,
+        total_flop_shares: market.flop_shares,
+        payout_per_share: market.payout_per_share,
+    });
+
+    Ok(())
+}
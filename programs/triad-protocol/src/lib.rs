@@ -6,6 +6,7 @@ mod constants;
 mod constraints;
 mod errors;
 mod events;
+mod utils;
 pub mod instructions;
 pub mod state;
 
@@ -26,10 +27,25 @@ pub mod triad_protocol {
         instructions::initialize_market(ctx, args)
     }
 
-    pub fn collect_fee(ctx: Context<CollectFee>) -> Result<()> {
+    pub fn create_market<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CreateMarket<'info>>,
+        args: CreateMarketArgs
+    ) -> Result<()> {
+        instructions::create_market(ctx, args)
+    }
+
+    pub fn collect_fee<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CollectFee<'info>>
+    ) -> Result<()> {
         instructions::collect_fee(ctx)
     }
 
+    pub fn collect_creator_fee<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CollectCreatorFee<'info>>
+    ) -> Result<()> {
+        instructions::collect_creator_fee(ctx)
+    }
+
     pub fn resolve_market(
         ctx: Context<ResolveMarket>,
         winning_direction: WinningDirection
@@ -37,6 +53,16 @@ pub mod triad_protocol {
         instructions::resolve_market(ctx, winning_direction)
     }
 
+    pub fn void_market(ctx: Context<VoidMarket>) -> Result<()> {
+        instructions::void_market(ctx)
+    }
+
+    pub fn settle_market_bond<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SettleMarketBond<'info>>
+    ) -> Result<()> {
+        instructions::settle_market_bond(ctx)
+    }
+
     pub fn resolve_market_v1(
         ctx: Context<ResolveMarketV1>,
         winning_direction: WinningDirection
@@ -44,7 +70,59 @@ pub mod triad_protocol {
         instructions::resolve_market_v1(ctx, winning_direction)
     }
 
-    pub fn add_liquidity(ctx: Context<AddLiquidity>, args: AddLiquidityArgs) -> Result<()> {
+    pub fn create_market_history(
+        ctx: Context<CreateMarketHistory>,
+        args: CreateMarketHistoryArgs
+    ) -> Result<()> {
+        instructions::create_market_history(ctx, args)
+    }
+
+    pub fn create_market_metadata(
+        ctx: Context<CreateMarketMetadata>,
+        args: MarketMetadataArgs
+    ) -> Result<()> {
+        instructions::create_market_metadata(ctx, args)
+    }
+
+    pub fn update_market_metadata(
+        ctx: Context<UpdateMarketMetadata>,
+        args: MarketMetadataArgs
+    ) -> Result<()> {
+        instructions::update_market_metadata(ctx, args)
+    }
+
+    pub fn create_season(ctx: Context<CreateSeason>, args: CreateSeasonArgs) -> Result<()> {
+        instructions::create_season(ctx, args)
+    }
+
+    pub fn fund_season<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, FundSeason<'info>>,
+        args: FundSeasonArgs
+    ) -> Result<()> {
+        instructions::fund_season(ctx, args)
+    }
+
+    pub fn join_season(ctx: Context<JoinSeason>) -> Result<()> {
+        instructions::join_season(ctx)
+    }
+
+    pub fn claim_season_prize<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimSeasonPrize<'info>>
+    ) -> Result<()> {
+        instructions::claim_season_prize(ctx)
+    }
+
+    pub fn update_market_limits(
+        ctx: Context<UpdateMarketLimits>,
+        args: UpdateMarketLimitsArgs
+    ) -> Result<()> {
+        instructions::update_market_limits(ctx, args)
+    }
+
+    pub fn add_liquidity<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, AddLiquidity<'info>>,
+        args: AddLiquidityArgs
+    ) -> Result<()> {
         instructions::add_liquidity(ctx, args)
     }
 
@@ -52,27 +130,164 @@ pub mod triad_protocol {
         instructions::create_user(ctx, args)
     }
 
+    pub fn create_referral_rewards(ctx: Context<CreateReferralRewards>) -> Result<()> {
+        instructions::create_referral_rewards(ctx)
+    }
+
+    pub fn claim_referral_rewards<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimReferralRewards<'info>>
+    ) -> Result<()> {
+        instructions::claim_referral_rewards(ctx)
+    }
+
     pub fn create_user_trade(ctx: Context<CreateUserTrade>) -> Result<()> {
         instructions::create_user_trade(ctx)
     }
 
-    pub fn open_order(ctx: Context<OpenOrder>, args: OpenOrderArgs) -> Result<()> {
+    pub fn open_order<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, OpenOrder<'info>>,
+        args: OpenOrderArgs
+    ) -> Result<()> {
         instructions::open_order(ctx, args)
     }
 
-    pub fn close_order(ctx: Context<CloseOrder>, order_id: u64) -> Result<()> {
-        instructions::close_order(ctx, order_id)
+    pub fn close_order<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CloseOrder<'info>>,
+        order_id: u64,
+        min_price: u64
+    ) -> Result<()> {
+        instructions::close_order(ctx, order_id, min_price)
     }
 
-    pub fn settle_order(ctx: Context<SettleOrder>, order_id: u64) -> Result<()> {
+    pub fn settle_order<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SettleOrder<'info>>,
+        order_id: u64
+    ) -> Result<()> {
         instructions::settle_order(ctx, order_id)
     }
 
-    pub fn payout_order(ctx: Context<PayoutOrder>, order_id: u64) -> Result<()> {
+    pub fn payout_order<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PayoutOrder<'info>>,
+        order_id: u64
+    ) -> Result<()> {
         instructions::payout_order(ctx, order_id)
     }
 
-    pub fn stake_token(ctx: Context<StakeToken>, args: StakeTokenArgs) -> Result<()> {
+    pub fn open_order_sol<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, OpenOrderSol<'info>>,
+        args: OpenOrderArgs
+    ) -> Result<()> {
+        instructions::open_order_sol(ctx, args)
+    }
+
+    pub fn close_order_sol<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CloseOrderSol<'info>>,
+        order_id: u64,
+        min_price: u64
+    ) -> Result<()> {
+        instructions::close_order_sol(ctx, order_id, min_price)
+    }
+
+    pub fn payout_order_sol<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PayoutOrderSol<'info>>,
+        order_id: u64
+    ) -> Result<()> {
+        instructions::payout_order_sol(ctx, order_id)
+    }
+
+    pub fn set_order_triggers(
+        ctx: Context<SetOrderTriggers>,
+        args: SetOrderTriggersArgs
+    ) -> Result<()> {
+        instructions::set_order_triggers(ctx, args)
+    }
+
+    pub fn execute_order_trigger<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ExecuteOrderTrigger<'info>>,
+        order_id: u64
+    ) -> Result<()> {
+        instructions::execute_order_trigger(ctx, order_id)
+    }
+
+    pub fn create_batch_auction(
+        ctx: Context<CreateBatchAuction>,
+        args: CreateBatchAuctionArgs
+    ) -> Result<()> {
+        instructions::create_batch_auction(ctx, args)
+    }
+
+    pub fn submit_batch_order<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SubmitBatchOrder<'info>>,
+        args: SubmitBatchOrderArgs
+    ) -> Result<()> {
+        instructions::submit_batch_order(ctx, args)
+    }
+
+    pub fn clear_batch<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClearBatch<'info>>
+    ) -> Result<()> {
+        instructions::clear_batch(ctx)
+    }
+
+    pub fn payout_orders<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PayoutOrders<'info>>
+    ) -> Result<()> {
+        instructions::payout_orders(ctx)
+    }
+
+    pub fn payout_all<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PayoutAll<'info>>
+    ) -> Result<()> {
+        instructions::payout_all(ctx)
+    }
+
+    pub fn verify_market(ctx: Context<VerifyMarket>) -> Result<SolvencyReport> {
+        instructions::verify_market(ctx)
+    }
+
+    pub fn observe_price(ctx: Context<ObservePrice>) -> Result<PriceObservation> {
+        instructions::observe_price(ctx)
+    }
+
+    pub fn create_voting_market(
+        ctx: Context<CreateVotingMarket>,
+        args: CreateVotingMarketArgs
+    ) -> Result<()> {
+        instructions::create_voting_market(ctx, args)
+    }
+
+    pub fn vote<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Vote<'info>>,
+        args: VoteArgs
+    ) -> Result<()> {
+        instructions::vote(ctx, args)
+    }
+
+    pub fn promote_voting_market(
+        ctx: Context<PromoteVotingMarket>,
+        args: PromoteVotingMarketArgs
+    ) -> Result<()> {
+        instructions::promote_voting_market(ctx, args)
+    }
+
+    pub fn mint_complete_set<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, MintCompleteSet<'info>>,
+        args: MintCompleteSetArgs
+    ) -> Result<()> {
+        instructions::mint_complete_set(ctx, args)
+    }
+
+    pub fn redeem_complete_set<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RedeemCompleteSet<'info>>,
+        args: RedeemCompleteSetArgs
+    ) -> Result<()> {
+        instructions::redeem_complete_set(ctx, args)
+    }
+
+    pub fn stake_token<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, StakeToken<'info>>,
+        args: StakeTokenArgs
+    ) -> Result<()> {
         instructions::stake_token(ctx, args)
     }
 
@@ -80,19 +295,21 @@ pub mod triad_protocol {
         instructions::request_withdraw_stake(ctx)
     }
 
-    pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+    pub fn withdraw_stake<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, WithdrawStake<'info>>
+    ) -> Result<()> {
         instructions::withdraw_stake(ctx)
     }
 
-    pub fn update_stake_vault(
-        ctx: Context<UpdateStakeVault>,
+    pub fn update_stake_vault<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateStakeVault<'info>>,
         args: UpdateStakeVaultArgs
     ) -> Result<()> {
         instructions::update_stake_vault(ctx, args)
     }
 
-    pub fn claim_stake_rewards(
-        ctx: Context<ClaimStakeRewards>,
+    pub fn claim_stake_rewards<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimStakeRewards<'info>>,
         args: ClaimStakeRewardsArgs
     ) -> Result<u64> {
         instructions::claim_stake_rewards(ctx, args)
@@ -112,4 +329,27 @@ pub mod triad_protocol {
     pub fn mint_ticket(ctx: Context<MintTicket>, args: MintTicketArgs) -> Result<()> {
         instructions::mint_ticket(ctx, args)
     }
+
+    pub fn create_nft_reward_pool(
+        ctx: Context<CreateNftRewardPool>,
+        args: CreateNftRewardPoolArgs
+    ) -> Result<()> {
+        instructions::create_nft_reward_pool(ctx, args)
+    }
+
+    pub fn fund_nft_reward_pool<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, FundNftRewardPool<'info>>
+    ) -> Result<()> {
+        instructions::fund_nft_reward_pool(ctx)
+    }
+
+    pub fn advance_nft_reward_epoch(ctx: Context<AdvanceNftRewardEpoch>) -> Result<()> {
+        instructions::advance_nft_reward_epoch(ctx)
+    }
+
+    pub fn claim_nft_rewards<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimNftRewards<'info>>
+    ) -> Result<()> {
+        instructions::claim_nft_rewards(ctx)
+    }
 }
@@ -26,7 +26,7 @@ pub struct MintTicketArgs {
     pub rarity: Rarity,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
 pub enum Rarity {
     Common,
     Uncommon,
@@ -39,9 +39,75 @@ pub enum Rarity {
 #[account]
 pub struct Nft {}
 
+/// Pool of the NFT holders' share of trading fees, paid out per epoch to every ticket
+/// of the collection
+#[account]
+pub struct NftRewardPool {
+    pub bump: u8,
+    pub collection: Pubkey,
+    pub core_collection: Pubkey,
+    pub mint: Pubkey,
+    /// Last closed epoch, the only one tickets can claim
+    pub epoch: u64,
+    pub epoch_start: i64,
+    /// Length of each epoch (in seconds)
+    pub epoch_duration: i64,
+    /// Fees received since the last epoch was closed (in TRD)
+    pub pending_rewards: u64,
+    /// Reward each ticket can claim for `epoch` (in TRD)
+    pub reward_per_asset: u64,
+    pub epoch_rewards: u64,
+    pub epoch_claimed: u64,
+    pub total_claimed: u64,
+    pub padding: [u8; 64],
+}
+
+/// Last epoch claimed by a ticket, keyed by the pool and the asset so it follows the ticket
+/// when sold. Created at mint with the last closed epoch, so a ticket only shares the epochs
+/// closed after it was minted.
+#[account]
+pub struct NftRewardClaim {
+    pub bump: u8,
+    pub reward_pool: Pubkey,
+    pub asset: Pubkey,
+    pub epoch: u64,
+    pub total_claimed: u64,
+    pub padding: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateNftRewardPoolArgs {
+    pub epoch_duration: i64,
+}
+
 impl Collection {
     pub const PREFIX_SEED: &'static [u8] = b"collection";
     pub const NFT_PREFIX_SEED: &'static [u8] = b"nft";
 
     pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
 }
+
+impl NftRewardPool {
+    pub const PREFIX_SEED: &'static [u8] = b"nft_reward_pool";
+
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+
+    /// Closes the running epoch, unclaimed rewards of the previous one roll over
+    pub fn advance_epoch(&mut self, tickets: u64, ts: i64) {
+        let unclaimed = self.epoch_rewards.saturating_sub(self.epoch_claimed);
+        let rewards = self.pending_rewards.checked_add(unclaimed).unwrap();
+
+        self.reward_per_asset = rewards.checked_div(tickets).unwrap();
+        self.epoch_rewards = self.reward_per_asset.checked_mul(tickets).unwrap();
+        self.pending_rewards = rewards.checked_sub(self.epoch_rewards).unwrap();
+        self.epoch_claimed = 0;
+        self.epoch = self.epoch.checked_add(1).unwrap();
+        self.epoch_start = ts;
+    }
+}
+
+impl NftRewardClaim {
+    pub const PREFIX_SEED: &'static [u8] = b"nft_reward_claim";
+
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ MarketV2, MarketMode, OrderDirection },
+    errors::TriadProtocolError,
+    events::PriceUpdate,
+};
+
+/// Companion of a `MarketMode::BatchAuction` market collecting the orders of the current
+/// batch window until a crank clears them at a single price
+#[account]
+pub struct BatchAuction {
+    pub bump: u8,
+    pub market: Pubkey,
+    /// Length of each batch window (in seconds)
+    pub batch_duration: i64,
+    /// Batch currently collecting or being cleared
+    pub batch_id: u64,
+    /// Orders are accepted until this timestamp, then the batch waits for the crank
+    pub batch_end: i64,
+    /// Collateral submitted to each side during the batch, fees excluded (in TRD)
+    pub hype_amount: u64,
+    pub flop_amount: u64,
+    /// Orders of the batch not filled yet
+    pub pending_orders: u64,
+    /// Set once the clearing prices are fixed, until every order of the batch is filled
+    pub is_clearing: bool,
+    /// Uniform prices of the batch being cleared (0-1000000, representing 0 to 1 TRD)
+    pub clearing_hype_price: u64,
+    pub clearing_flop_price: u64,
+    pub padding: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateBatchAuctionArgs {
+    pub batch_duration: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SubmitBatchOrderArgs {
+    pub amount: u64,
+    pub direction: OrderDirection,
+}
+
+impl BatchAuction {
+    pub const PREFIX_SEED: &'static [u8] = b"batch_auction";
+
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+
+    /// Batch auction markets can only be resolved or voided once every order of the last batch
+    /// has been filled, the payouts only settle open orders
+    pub fn check_cleared(market: &MarketV2, batch_auction: Option<&BatchAuction>) -> Result<()> {
+        if market.mode != MarketMode::BatchAuction {
+            return Ok(());
+        }
+
+        let batch_auction = batch_auction.ok_or(TriadProtocolError::BatchNotCleared)?;
+
+        require!(
+            batch_auction.pending_orders == 0 && !batch_auction.is_clearing,
+            TriadProtocolError::BatchNotCleared
+        );
+
+        Ok(())
+    }
+
+    /// Opens the next window once the previous batch has been fully cleared. Windows never
+    /// outlive the market so every batch can be cleared before resolution.
+    pub fn roll_window(&mut self, ts: i64, market_end: i64) {
+        if !self.is_clearing && self.pending_orders == 0 && ts >= self.batch_end {
+            self.batch_id = self.batch_id.checked_add(1).unwrap();
+            self.batch_end = ts.checked_add(self.batch_duration).unwrap().min(market_end);
+            self.hype_amount = 0;
+            self.flop_amount = 0;
+        }
+    }
+
+    pub fn add_order(&mut self, direction: OrderDirection, amount: u64) {
+        match direction {
+            OrderDirection::Hype => {
+                self.hype_amount = self.hype_amount.checked_add(amount).unwrap();
+            }
+            OrderDirection::Flop => {
+                self.flop_amount = self.flop_amount.checked_add(amount).unwrap();
+            }
+        }
+
+        self.pending_orders = self.pending_orders.checked_add(1).unwrap();
+    }
+
+    /// Adds the whole batch to the pool liquidity at once and fixes the resulting prices,
+    /// so every order of the batch fills at the same price whatever its position in the batch
+    pub fn start_clearing(&mut self, market: &mut MarketV2, ts: i64) -> PriceUpdate {
+        market.accumulate_price(ts);
+
+        market.hype_liquidity = market.hype_liquidity.checked_add(self.hype_amount).unwrap();
+        market.flop_liquidity = market.flop_liquidity.checked_add(self.flop_amount).unwrap();
+
+        let hype_price = (market.hype_liquidity as u128)
+            .checked_mul(1_000_000)
+            .unwrap()
+            .checked_div(
+                (market.hype_liquidity as u128).checked_add(market.flop_liquidity as u128).unwrap()
+            )
+            .unwrap() as u64;
+
+        market.hype_price = hype_price.clamp(1, 999_999);
+        market.flop_price = 1_000_000 - market.hype_price;
+
+        self.clearing_hype_price = market.hype_price;
+        self.clearing_flop_price = market.flop_price;
+        self.is_clearing = true;
+
+        PriceUpdate {
+            market_id: market.market_id,
+            seq: market.next_event_seq(),
+            hype_price: market.hype_price,
+            flop_price: market.flop_price,
+            direction: if self.hype_amount >= self.flop_amount {
+                OrderDirection::Hype
+            } else {
+                OrderDirection::Flop
+            },
+            timestamp: ts,
+        }
+    }
+
+    pub fn clearing_price(&self, direction: OrderDirection) -> u64 {
+        match direction {
+            OrderDirection::Hype => self.clearing_hype_price,
+            OrderDirection::Flop => self.clearing_flop_price,
+        }
+    }
+
+    /// Marks one order of the batch as filled, closing the batch after the last one
+    pub fn fill_order(&mut self) {
+        self.pending_orders = self.pending_orders.checked_sub(1).unwrap();
+
+        if self.pending_orders == 0 {
+            self.is_clearing = false;
+        }
+    }
+}
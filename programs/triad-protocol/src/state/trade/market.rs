@@ -49,7 +49,9 @@ pub struct Market {
     /// The question or prediction topic for the current week
     pub current_question: [u8; 80],
     pub liquidity: u64,
-    pub padding: [u8; 200],
+    /// Sequence number of the last event emitted for this market
+    pub event_seq: u64,
+    pub padding: [u8; 192],
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
@@ -134,7 +136,8 @@ impl Default for Market {
             current_question_end: 0,
             current_question: [0; 80],
             liquidity: 0,
-            padding: [0; 200],
+            event_seq: 0,
+            padding: [0; 192],
         }
     }
 }
@@ -143,4 +146,9 @@ impl Market {
     pub const PREFIX_SEED: &'static [u8] = b"market";
 
     pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+
+    pub fn next_event_seq(&mut self) -> u64 {
+        self.event_seq = self.event_seq.checked_add(1).unwrap();
+        self.event_seq
+    }
 }
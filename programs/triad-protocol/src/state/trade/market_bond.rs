@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// Bond posted by the creator of a permissionless market
+#[account]
+pub struct MarketBond {
+    pub bump: u8,
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    /// Account allowed to resolve the market alongside the admin
+    pub resolver: Pubkey,
+    /// Bond posted by the creator (in TRD)
+    pub amount: u64,
+    /// Collateral seeded into the market pools by the creator (in TRD)
+    pub seed_liquidity: u64,
+    pub status: BondStatus,
+    pub padding: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BondStatus {
+    Posted,
+    /// Returned to the creator after a clean resolution
+    Returned,
+    /// Sent to the protocol after the market was voided
+    Slashed,
+}
+
+impl MarketBond {
+    pub const PREFIX_SEED: &'static [u8] = b"market_bond";
+
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+}
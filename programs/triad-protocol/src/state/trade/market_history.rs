@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+/// Ring buffer of Hype price candles for a market, prices follow `MarketV2::hype_price`
+#[account(zero_copy)]
+pub struct MarketHistory {
+    pub market: Pubkey,
+    /// Length of each candle (in seconds)
+    pub interval: i64,
+    /// Index of the latest candle
+    pub head: u64,
+    /// Number of candles written, up to `MAX_CANDLES`
+    pub len: u64,
+    pub candles: [Candle; MarketHistory::MAX_CANDLES],
+}
+
+#[zero_copy]
+#[derive(Default)]
+pub struct Candle {
+    pub start_ts: i64,
+    pub open: u64,
+    pub high: u64,
+    pub low: u64,
+    pub close: u64,
+    /// Traded volume (in TRD)
+    pub volume: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateMarketHistoryArgs {
+    pub interval: i64,
+}
+
+impl MarketHistory {
+    pub const PREFIX_SEED: &'static [u8] = b"market_history";
+
+    pub const MAX_CANDLES: usize = 128;
+
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+
+    pub fn record_trade(&mut self, ts: i64, price: u64, volume: u64) {
+        let start_ts = ts - ts.rem_euclid(self.interval);
+        let head = self.head as usize;
+
+        if self.len > 0 && self.candles[head].start_ts == start_ts {
+            let candle = &mut self.candles[head];
+            candle.high = candle.high.max(price);
+            candle.low = candle.low.min(price);
+            candle.close = price;
+            candle.volume = candle.volume.saturating_add(volume);
+
+            return;
+        }
+
+        // A new candle opens at the previous close so gaps between trades stay continuous
+        let open = if self.len > 0 { self.candles[head].close } else { price };
+
+        if self.len > 0 {
+            self.head = ((head + 1) % Self::MAX_CANDLES) as u64;
+        }
+
+        self.len = (self.len + 1).min(Self::MAX_CANDLES as u64);
+        self.candles[self.head as usize] = Candle {
+            start_ts,
+            open,
+            high: open.max(price),
+            low: open.min(price),
+            close: price,
+            volume,
+        };
+    }
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TriadProtocolError;
+
+/// Display and resolution details of a market, sized to its content
+#[account]
+pub struct MarketMetadata {
+    pub bump: u8,
+    pub market: Pubkey,
+    pub update_ts: i64,
+    pub question: String,
+    /// Criteria used to resolve the market
+    pub rules: String,
+    pub sources: Vec<String>,
+    pub category: String,
+    pub tags: Vec<String>,
+    pub image: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MarketMetadataArgs {
+    pub question: String,
+    pub rules: String,
+    pub sources: Vec<String>,
+    pub category: String,
+    pub tags: Vec<String>,
+    pub image: String,
+}
+
+impl MarketMetadata {
+    pub const PREFIX_SEED: &'static [u8] = b"market_metadata";
+
+    pub const MAX_QUESTION_LEN: usize = 512;
+    pub const MAX_RULES_LEN: usize = 2048;
+    pub const MAX_SOURCES: usize = 5;
+    pub const MAX_URL_LEN: usize = 200;
+    pub const MAX_CATEGORY_LEN: usize = 32;
+    pub const MAX_TAGS: usize = 8;
+    pub const MAX_TAG_LEN: usize = 32;
+
+    pub fn space(args: &MarketMetadataArgs) -> usize {
+        let strings_len = |strings: &[String]| {
+            4 +
+                strings
+                    .iter()
+                    .map(|string| 4 + string.len())
+                    .sum::<usize>()
+        };
+
+        8 +
+            1 +
+            32 +
+            8 +
+            (4 + args.question.len()) +
+            (4 + args.rules.len()) +
+            strings_len(&args.sources) +
+            (4 + args.category.len()) +
+            strings_len(&args.tags) +
+            (4 + args.image.len())
+    }
+
+    pub fn validate(args: &MarketMetadataArgs) -> Result<()> {
+        require!(
+            !args.question.is_empty() &&
+                args.question.len() <= Self::MAX_QUESTION_LEN &&
+                args.rules.len() <= Self::MAX_RULES_LEN &&
+                args.sources.len() <= Self::MAX_SOURCES &&
+                args.sources.iter().all(|source| source.len() <= Self::MAX_URL_LEN) &&
+                args.category.len() <= Self::MAX_CATEGORY_LEN &&
+                args.tags.len() <= Self::MAX_TAGS &&
+                args.tags.iter().all(|tag| tag.len() <= Self::MAX_TAG_LEN) &&
+                args.image.len() <= Self::MAX_URL_LEN,
+            TriadProtocolError::InvalidMarketMetadata
+        );
+
+        Ok(())
+    }
+
+    pub fn set_content(&mut self, args: MarketMetadataArgs, ts: i64) {
+        self.question = args.question;
+        self.rules = args.rules;
+        self.sources = args.sources;
+        self.category = args.category;
+        self.tags = args.tags;
+        self.image = args.image;
+        self.update_ts = ts;
+    }
+}
@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::{ state::OrderDirection, events::PriceUpdate };
+use crate::{
+    state::{ Order, OrderDirection },
+    events::PriceUpdate,
+    errors::TriadProtocolError,
+    constants::{ BASE_DECIMALS, TRIGGER_TWAP_WINDOW, VIRTUAL_LIQUIDITY },
+};
 
 #[account]
 pub struct MarketV2 {
@@ -45,7 +50,49 @@ pub struct MarketV2 {
     pub question: [u8; 80],
     pub winning_direction: WinningDirection,
     pub market_liquidity_at_start: u64,
-    pub padding: [u8; 92],
+    /// Winning side shares outstanding at resolution, open interest for a voided market
+    pub settled_winning_shares: u64,
+    /// Collateral owed to the winning side at resolution, seed liquidity excluded (in TRD)
+    pub settled_pool: u64,
+    /// Seed liquidity excluded from the settlement pool, 0 until the market is resolved
+    pub settled_seed_liquidity: u64,
+    /// Fixed payout per winning share, or per TRD of cost basis for a voided market
+    /// (0-1000000, representing 0 to 1 TRD)
+    pub payout_per_share: u64,
+    /// Total paid out to winning orders since resolution (in TRD)
+    pub settled_claimed: u64,
+    /// Share of the market fee paid to the market creator (in basis points of the market fee)
+    pub creator_fee_bps: u16,
+    /// Collateral committed by the open orders (in TRD)
+    pub open_interest: u64,
+    /// Most collateral a single user can hold in open orders, 0 for no limit (in TRD)
+    pub max_user_stake: u64,
+    /// Largest order relative to the market liquidity, 0 for no limit (in basis points)
+    pub max_order_liquidity_bps: u16,
+    /// Most collateral the open orders can hold in total, 0 for no limit (in TRD)
+    pub max_open_interest: u64,
+    /// Running sum of `hype_price` weighted by the seconds it was quoted, wraps on overflow.
+    /// Advanced to `update_ts`.
+    pub hype_price_cumulative: u64,
+    /// Sequence number of the last event emitted for this market
+    pub event_seq: u64,
+    /// Share of the market fee paid to the trader's referrer, 0 until set by the market
+    /// authority (in basis points of the market fee)
+    pub referral_fee_bps: u16,
+    /// Decimals of the collateral mint, 0 for markets created before they were recorded (TRD)
+    pub collateral_decimals: u8,
+    /// How orders are priced, batch auction markets carry a `BatchAuction` companion
+    pub mode: MarketMode,
+    /// Whether a `MarketHistory` exists, trades must then record their candle
+    pub has_history: bool,
+    /// Accumulator checkpoint at least `TRIGGER_TWAP_WINDOW` old, order triggers fire on the
+    /// TWAP since then
+    pub twap_checkpoint_cumulative: u64,
+    pub twap_checkpoint_ts: i64,
+    /// Latest checkpoint, replaces the one above once a full window old
+    pub next_checkpoint_cumulative: u64,
+    pub next_checkpoint_ts: i64,
+    pub padding: [u8; 3],
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
@@ -53,6 +100,18 @@ pub enum WinningDirection {
     None,
     Hype,
     Flop,
+    /// Market cancelled, open orders are refunded their cost basis
+    Void,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarketMode {
+    /// Every order moves the price as it lands
+    #[default]
+    Amm,
+    /// Orders are collected per window and filled at a single clearing price. Positions are
+    /// held until the market is settled, `close_order` only trades against the AMM.
+    BatchAuction,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -64,6 +123,63 @@ pub struct InitializeMarketArgs {
     pub end_time: i64,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SolvencyReport {
+    pub market_id: u64,
+    /// Balance of the market vault (in TRD)
+    pub vault_balance: u64,
+    /// Most the market can still owe to traders (in TRD)
+    pub outstanding_liabilities: u64,
+    /// Fees accrued and not yet collected (in TRD)
+    pub unclaimed_fees: u64,
+    pub is_solvent: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateMarketArgs {
+    pub market_id: u64,
+    pub question: [u8; 80],
+    pub start_time: i64,
+    pub end_time: i64,
+    /// Account allowed to resolve the market alongside the admin
+    pub resolver: Pubkey,
+    /// Collateral seeded into the pools, split evenly between Hype and Flop
+    pub seed_liquidity: u64,
+    /// Share of the market fee paid to the creator (in basis points of the market fee)
+    pub creator_fee_bps: u16,
+}
+
+/// Snapshot of the price accumulator, two observations of the same market give the TWAP between them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PriceObservation {
+    pub market_id: u64,
+    pub hype_price_cumulative: u64,
+    pub timestamp: i64,
+    pub hype_price: u64,
+    pub flop_price: u64,
+}
+
+impl PriceObservation {
+    /// Time weighted average Hype price between this observation and a later one, the Flop TWAP
+    /// being `1_000_000 - twap`
+    pub fn twap(&self, later: &PriceObservation) -> Result<u64> {
+        require!(self.market_id == later.market_id, TriadProtocolError::InvalidPrice);
+        require!(later.timestamp > self.timestamp, TriadProtocolError::InvalidPrice);
+
+        let elapsed = (later.timestamp - self.timestamp) as u64;
+
+        Ok(later.hype_price_cumulative.wrapping_sub(self.hype_price_cumulative) / elapsed)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateMarketLimitsArgs {
+    pub max_user_stake: Option<u64>,
+    pub max_order_liquidity_bps: Option<u16>,
+    pub max_open_interest: Option<u64>,
+    pub referral_fee_bps: Option<u16>,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct AddLiquidityArgs {
     pub amount: u64,
@@ -78,8 +194,8 @@ impl Default for MarketV2 {
             market_id: 0,
             hype_price: 500_000, // Initial price set to 0.5 TRD
             flop_price: 500_000, // Initial price set to 0.5 TRD
-            hype_liquidity: 5_000_000_000,
-            flop_liquidity: 5_000_000_000,
+            hype_liquidity: VIRTUAL_LIQUIDITY,
+            flop_liquidity: VIRTUAL_LIQUIDITY,
             mint: Pubkey::default(),
             update_ts: 0,
             next_order_id: 0,
@@ -100,8 +216,28 @@ impl Default for MarketV2 {
             market_fee_claimed: 0,
             winning_direction: WinningDirection::None,
             question: [0; 80],
-            market_liquidity_at_start: 10_000_000_000,
-            padding: [0; 92],
+            market_liquidity_at_start: VIRTUAL_LIQUIDITY * 2,
+            settled_winning_shares: 0,
+            settled_pool: 0,
+            settled_seed_liquidity: 0,
+            payout_per_share: 0,
+            settled_claimed: 0,
+            creator_fee_bps: 0,
+            open_interest: 0,
+            max_user_stake: 0,
+            max_order_liquidity_bps: 0,
+            max_open_interest: 0,
+            hype_price_cumulative: 0,
+            event_seq: 0,
+            referral_fee_bps: 0,
+            collateral_decimals: BASE_DECIMALS,
+            mode: MarketMode::Amm,
+            has_history: false,
+            twap_checkpoint_cumulative: 0,
+            twap_checkpoint_ts: 0,
+            next_checkpoint_cumulative: 0,
+            next_checkpoint_ts: 0,
+            padding: [0; 3],
         }
     }
 }
@@ -117,23 +253,399 @@ impl MarketV2 {
         id
     }
 
+    /// Converts an amount given with `BASE_DECIMALS` to a mint with `decimals`
+    pub fn scale_amount(amount: u64, decimals: u8) -> Result<u64> {
+        let scaled = if decimals >= BASE_DECIMALS {
+            10u64
+                .checked_pow((decimals - BASE_DECIMALS) as u32)
+                .and_then(|factor| amount.checked_mul(factor))
+        } else {
+            10u64
+                .checked_pow((BASE_DECIMALS - decimals) as u32)
+                .and_then(|factor| amount.checked_div(factor))
+        };
+
+        scaled.ok_or(TriadProtocolError::InvalidMarketParameters.into())
+    }
+
+    pub fn decimals(&self) -> u8 {
+        if self.collateral_decimals == 0 { BASE_DECIMALS } else { self.collateral_decimals }
+    }
+
+    /// Records the collateral decimals of a new market and sizes its virtual liquidity to them
+    pub fn set_collateral_decimals(&mut self, decimals: u8) -> Result<()> {
+        require!(decimals > 0, TriadProtocolError::InvalidMarketParameters);
+
+        let virtual_liquidity = Self::scale_amount(VIRTUAL_LIQUIDITY, decimals)?;
+
+        self.collateral_decimals = decimals;
+        self.hype_liquidity = virtual_liquidity;
+        self.flop_liquidity = virtual_liquidity;
+        self.market_liquidity_at_start = virtual_liquidity.checked_mul(2).unwrap();
+
+        Ok(())
+    }
+
+    /// Sequence number for the next market event, gaps tell indexers an event was missed
+    pub fn next_event_seq(&mut self) -> u64 {
+        self.event_seq = self.event_seq.checked_add(1).unwrap();
+        self.event_seq
+    }
+
+    /// Seed liquidity shared by both sides at creation, not owed to traders
+    pub fn seed_liquidity(&self) -> u64 {
+        if self.market_liquidity_at_start == 0 {
+            1_000_000_000
+        } else {
+            self.market_liquidity_at_start
+        }
+    }
+
+    /// Collateral deposited by traders across both sides, seed liquidity excluded (in TRD)
+    pub fn net_liquidity(&self) -> u64 {
+        let seed_per_side = self.seed_liquidity().checked_div(2).unwrap();
+
+        self.hype_liquidity
+            .saturating_sub(seed_per_side)
+            .checked_add(self.flop_liquidity.saturating_sub(seed_per_side))
+            .unwrap()
+    }
+
+    /// Snapshots the final pool and the payout per winning share once the winning direction is set
+    pub fn snapshot_settlement(&mut self) -> Result<()> {
+        let winning_shares = match self.winning_direction {
+            WinningDirection::Hype => self.hype_shares,
+            WinningDirection::Flop => self.flop_shares,
+            WinningDirection::Void => self.open_interest,
+            WinningDirection::None => {
+                return Err(TriadProtocolError::MarketNotResolved.into());
+            }
+        };
+
+        let pool = self.net_liquidity();
+
+        let payout_per_share = if winning_shares == 0 {
+            0
+        } else {
+            ((pool as u128) * 1_000_000)
+                .checked_div(winning_shares as u128)
+                .unwrap()
+                .min(1_000_000) as u64
+        };
+
+        self.settled_winning_shares = winning_shares;
+        self.settled_pool = pool;
+        self.settled_seed_liquidity = self.seed_liquidity();
+        self.payout_per_share = payout_per_share;
+
+        Ok(())
+    }
+
+    /// Amount owed to `order` once the market is resolved and whether it is on the winning side.
+    /// Orders of a voided market are refunded their cost basis.
+    pub fn order_payout(&self, order: &Order) -> Result<(u64, bool)> {
+        let is_winner = matches!(
+            (order.direction, self.winning_direction),
+            (OrderDirection::Hype, WinningDirection::Hype) |
+                (OrderDirection::Flop, WinningDirection::Flop)
+        );
+
+        let is_void = self.winning_direction == WinningDirection::Void;
+
+        if !is_winner && !is_void {
+            return Ok((0, false));
+        }
+
+        // Market resolved before settlement snapshots were recorded, earlier claims were paid
+        // under the legacy formula so later ones must be too
+        if self.settled_seed_liquidity == 0 {
+            return Ok((self.legacy_order_payout(order), is_winner));
+        }
+
+        let payout_per_share = self.payout_per_share;
+
+        let basis = if is_void { order.total_amount } else { order.total_shares };
+
+        let payout = ((basis as u128) * (payout_per_share as u128))
+            .checked_div(1_000_000)
+            .unwrap() as u64;
+
+        Ok((payout, is_winner))
+    }
+
+    /// Payout of a winning `order` as computed before settlement snapshots: shares are paid 1:1
+    /// when the losing side covers them, otherwise the order gets its cost basis back plus its
+    /// share of the losing liquidity
+    fn legacy_order_payout(&self, order: &Order) -> u64 {
+        let (market_shares, opposite_liquidity) = match self.winning_direction {
+            WinningDirection::Hype => (self.hype_shares, self.flop_liquidity),
+            WinningDirection::Flop => (self.flop_shares, self.hype_liquidity),
+            _ => (0, 0),
+        };
+
+        let market_liquidity = opposite_liquidity.saturating_sub(
+            self.seed_liquidity().checked_div(2).unwrap()
+        );
+
+        let payout = if market_liquidity >= market_shares {
+            order.total_shares
+        } else {
+            let shares_ratio = (order.total_shares as f64) / (market_shares as f64);
+            let additional_payout = (shares_ratio * (market_liquidity as f64)).round() as u64;
+
+            additional_payout.checked_add(order.total_amount).unwrap()
+        };
+
+        payout.min(order.total_shares)
+    }
+
+    /// Current price of `direction` shares
+    pub fn price(&self, direction: OrderDirection) -> u64 {
+        match direction {
+            OrderDirection::Hype => self.hype_price,
+            OrderDirection::Flop => self.flop_price,
+        }
+    }
+
+    /// Sells the shares of `order` back to the pool at the current price, returning the
+    /// refund owed to the trader and the price update when one happened
+    pub fn close_position(
+        &mut self,
+        order: &Order,
+        ts: i64
+    ) -> Result<(u64, Option<PriceUpdate>)> {
+        let (current_price, current_liquidity, otherside_current_liquidity) = match
+            order.direction
+        {
+            OrderDirection::Hype => (self.hype_price, self.hype_liquidity, self.flop_liquidity),
+            OrderDirection::Flop => (self.flop_price, self.flop_liquidity, self.hype_liquidity),
+        };
+
+        require!(current_liquidity > 0, TriadProtocolError::InsufficientLiquidity);
+
+        let current_amount = order.total_shares
+            .checked_mul(current_price)
+            .unwrap()
+            .checked_div(1_000_000)
+            .unwrap();
+
+        require!(current_liquidity > current_amount, TriadProtocolError::InsufficientLiquidity);
+
+        let new_directional_liquidity = current_liquidity.checked_sub(current_amount).unwrap();
+        let markets_liquidity = new_directional_liquidity
+            .checked_add(otherside_current_liquidity)
+            .unwrap();
+
+        let new_price = new_directional_liquidity
+            .checked_mul(1_000_000)
+            .unwrap()
+            .checked_div(markets_liquidity)
+            .unwrap()
+            .clamp(1, 999_999);
+
+        let price_update = if current_amount > 0 {
+            Some(self.update_price(current_amount, new_price, order.direction, false)?)
+        } else {
+            None
+        };
+
+        match order.direction {
+            OrderDirection::Hype => {
+                self.hype_shares = self.hype_shares.checked_sub(order.total_shares).unwrap();
+            }
+            OrderDirection::Flop => {
+                self.flop_shares = self.flop_shares.checked_sub(order.total_shares).unwrap();
+            }
+        }
+
+        self.volume = self.volume.checked_add(current_amount).unwrap();
+        self.opened_orders = self.opened_orders.checked_sub(1).unwrap();
+        self.open_interest = self.open_interest.saturating_sub(order.total_amount);
+        self.accumulate_price(ts);
+
+        Ok((current_amount, price_update))
+    }
+
+    /// Most the vault can owe traders: the larger side's shares while trading, capped at the
+    /// deposited liquidity the payout pays them from, and the unclaimed payouts once resolved
+    pub fn outstanding_liabilities(&self) -> u64 {
+        if self.winning_direction == WinningDirection::None {
+            return self.hype_shares.max(self.flop_shares).min(self.net_liquidity());
+        }
+
+        // Market resolved before settlement snapshots were recorded, the legacy formula pays
+        // each winning order at most its shares
+        if self.settled_seed_liquidity == 0 {
+            let winning_shares = match self.winning_direction {
+                WinningDirection::Hype => self.hype_shares,
+                WinningDirection::Flop => self.flop_shares,
+                _ => 0,
+            };
+
+            return winning_shares.saturating_sub(self.settled_claimed);
+        }
+
+        let owed = ((self.settled_winning_shares as u128) * (self.payout_per_share as u128))
+            .checked_div(1_000_000)
+            .unwrap() as u64;
+
+        owed.saturating_sub(self.settled_claimed)
+    }
+
+    pub fn unclaimed_fees(&self) -> u64 {
+        self.market_fee_available
+            .saturating_sub(self.market_fee_claimed)
+            .checked_add(self.nft_holders_fee_available.saturating_sub(self.nft_holders_fee_claimed))
+            .unwrap()
+            .checked_add(
+                self.market_maker_fee_available.saturating_sub(self.market_maker_fee_claimed)
+            )
+            .unwrap()
+    }
+
+    pub fn solvency_report(&self, vault_balance: u64) -> SolvencyReport {
+        let outstanding_liabilities = self.outstanding_liabilities();
+        let unclaimed_fees = self.unclaimed_fees();
+
+        SolvencyReport {
+            market_id: self.market_id,
+            vault_balance,
+            outstanding_liabilities,
+            unclaimed_fees,
+            is_solvent: vault_balance >=
+            outstanding_liabilities.checked_add(unclaimed_fees).unwrap(),
+        }
+    }
+
+    /// Fails if the vault balance no longer covers the outstanding liabilities and unclaimed fees
+    pub fn check_solvency(&self, vault_balance: u64) -> Result<()> {
+        // Claims paid before settlement snapshots were recorded are not tracked, so a legacy
+        // resolved market cannot be checked against what it still owes
+        if self.winning_direction != WinningDirection::None && self.settled_seed_liquidity == 0 {
+            return Ok(());
+        }
+
+        let report = self.solvency_report(vault_balance);
+
+        if !report.is_solvent {
+            msg!(
+                "Market {} insolvent: vault {} liabilities {} fees {}",
+                report.market_id,
+                report.vault_balance,
+                report.outstanding_liabilities,
+                report.unclaimed_fees
+            );
+            return Err(TriadProtocolError::MarketInsolvent.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks a new deposit of `amount` against the per-user and open interest limits, `user_stake`
+    /// being what the user already holds in open orders of this market
+    pub fn check_position_limits(&self, user_stake: u64, amount: u64) -> Result<()> {
+        if self.max_user_stake > 0 {
+            require!(
+                user_stake.checked_add(amount).unwrap() <= self.max_user_stake,
+                TriadProtocolError::UserStakeLimitExceeded
+            );
+        }
+
+        if self.max_open_interest > 0 {
+            require!(
+                self.open_interest.checked_add(amount).unwrap() <= self.max_open_interest,
+                TriadProtocolError::OpenInterestLimitExceeded
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Checks a single order of `amount` against the market liquidity
+    pub fn check_order_size(&self, amount: u64) -> Result<()> {
+        if self.max_order_liquidity_bps > 0 {
+            let max_order = ((self.hype_liquidity as u128) + (self.flop_liquidity as u128))
+                .checked_mul(self.max_order_liquidity_bps as u128)
+                .unwrap()
+                .checked_div(10000)
+                .unwrap();
+
+            require!((amount as u128) <= max_order, TriadProtocolError::OrderSizeLimitExceeded);
+        }
+
+        Ok(())
+    }
+
+    /// Accrues the current Hype price into the accumulator up to `ts` and moves `update_ts` there
+    pub fn accumulate_price(&mut self, ts: i64) {
+        self.hype_price_cumulative = self.price_cumulative(ts);
+        self.update_ts = self.update_ts.max(ts);
+
+        if self.update_ts - self.next_checkpoint_ts >= TRIGGER_TWAP_WINDOW {
+            self.twap_checkpoint_cumulative = self.next_checkpoint_cumulative;
+            self.twap_checkpoint_ts = self.next_checkpoint_ts;
+            self.next_checkpoint_cumulative = self.hype_price_cumulative;
+            self.next_checkpoint_ts = self.update_ts;
+        }
+    }
+
+    /// Price of `direction` averaged since the TWAP checkpoint, which trades in the current
+    /// second cannot move far from the price that held over the window
+    pub fn trigger_price(&self, direction: OrderDirection, ts: i64) -> Result<u64> {
+        // The first checkpoint is only promoted a window after the market's first trade
+        require!(
+            self.twap_checkpoint_ts > 0 && ts > self.twap_checkpoint_ts,
+            TriadProtocolError::PriceNotSettled
+        );
+
+        let elapsed = (ts - self.twap_checkpoint_ts) as u64;
+        let hype_twap = self
+            .price_cumulative(ts)
+            .wrapping_sub(self.twap_checkpoint_cumulative)
+            .checked_div(elapsed)
+            .unwrap();
+
+        Ok(match direction {
+            OrderDirection::Hype => hype_twap,
+            OrderDirection::Flop => (1_000_000u64).saturating_sub(hype_twap),
+        })
+    }
+
+    /// Value of the accumulator at `ts`, including the time elapsed since `update_ts`
+    pub fn price_cumulative(&self, ts: i64) -> u64 {
+        let elapsed = ts.saturating_sub(self.update_ts).max(0) as u64;
+
+        self.hype_price_cumulative.wrapping_add(self.hype_price.wrapping_mul(elapsed))
+    }
+
+    pub fn observe(&self, ts: i64) -> PriceObservation {
+        PriceObservation {
+            market_id: self.market_id,
+            hype_price_cumulative: self.price_cumulative(ts),
+            timestamp: ts,
+            hype_price: self.hype_price,
+            flop_price: self.flop_price,
+        }
+    }
+
     pub fn update_price(
         &mut self,
         amount: u64,
         future_price: u64,
         direction: OrderDirection,
         is_open: bool
-    ) -> Result<()> {
+    ) -> Result<PriceUpdate> {
+        let ts = Clock::get()?.unix_timestamp;
+
+        // Close the accumulator window at the price quoted until now
+        self.accumulate_price(ts);
+
         let current_price = match direction {
             OrderDirection::Hype => self.hype_price,
             OrderDirection::Flop => self.flop_price,
         };
 
-        let price_diff = if future_price > current_price {
-            future_price - current_price
-        } else {
-            current_price - future_price
-        };
+        let price_diff = future_price.abs_diff(current_price);
 
         let price_adjustment = ((price_diff as f64) / 1.02) as u64;
 
@@ -171,16 +683,13 @@ impl MarketV2 {
         self.hype_price = self.hype_price.clamp(1, 999_999);
         self.flop_price = self.flop_price.clamp(1, 999_999);
 
-        self.update_ts = Clock::get()?.unix_timestamp;
-
-        emit!(PriceUpdate {
+        Ok(PriceUpdate {
             market_id: self.market_id,
+            seq: self.next_event_seq(),
             hype_price: self.hype_price,
             flop_price: self.flop_price,
             direction,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-
-        Ok(())
+            timestamp: ts,
+        })
     }
 }
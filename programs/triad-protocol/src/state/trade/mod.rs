@@ -2,8 +2,22 @@ mod market;
 mod user_trade;
 mod fee_vault;
 mod market_v2;
+mod voting_market;
+mod market_bond;
+mod market_history;
+mod user_stats;
+mod season;
+mod market_metadata;
+mod batch_auction;
 
 pub use market::*;
 pub use user_trade::*;
 pub use fee_vault::*;
 pub use market_v2::*;
+pub use voting_market::*;
+pub use market_bond::*;
+pub use market_history::*;
+pub use user_stats::*;
+pub use season::*;
+pub use market_metadata::*;
+pub use batch_auction::*;
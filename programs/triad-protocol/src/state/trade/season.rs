@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TriadProtocolError;
+
+/// Trading competition over a time window, the top of `leaderboard` shares the prize pool
+#[account]
+pub struct Season {
+    pub bump: u8,
+    pub authority: Pubkey,
+    pub season_id: u64,
+    pub mint: Pubkey,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub score_type: SeasonScoreType,
+    /// Markets counted by the season, every market when empty
+    pub eligible_markets: [u64; 16],
+    pub eligible_markets_len: u8,
+    /// Funded prizes (in TRD)
+    pub prize_pool: u64,
+    /// Share of the prize pool for each rank (in basis points)
+    pub prize_bps: [u16; 10],
+    /// Bit `i` is set once rank `i` claimed its prize
+    pub claimed_ranks: u16,
+    /// Best scores, highest first
+    pub leaderboard: [SeasonEntry; 10],
+    /// Prize pool frozen by the first claim, every rank is paid from it
+    pub settled_prize_pool: u64,
+    pub padding: [u8; 24],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct SeasonEntry {
+    pub user: Pubkey,
+    pub score: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SeasonScoreType {
+    Pnl,
+    Volume,
+}
+
+/// A user's score in a season, created by `join_season`
+#[account]
+pub struct SeasonScore {
+    pub bump: u8,
+    pub season_id: u64,
+    pub authority: Pubkey,
+    pub score: i64,
+    pub volume: u64,
+    pub pnl: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateSeasonArgs {
+    pub season_id: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub score_type: SeasonScoreType,
+    pub eligible_markets: Vec<u64>,
+    pub prize_bps: Vec<u16>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FundSeasonArgs {
+    pub amount: u64,
+}
+
+impl Season {
+    pub const PREFIX_SEED: &'static [u8] = b"season";
+
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+
+    pub fn is_eligible(&self, market_id: u64) -> bool {
+        self.eligible_markets_len == 0 ||
+            self.eligible_markets[..self.eligible_markets_len as usize].contains(&market_id)
+    }
+
+    /// Adds a trade to the user's score, trades outside the window or the eligible markets
+    /// are ignored
+    pub fn record_trade(
+        &mut self,
+        season_score: &mut SeasonScore,
+        market_id: u64,
+        volume: u64,
+        pnl: i64,
+        ts: i64
+    ) -> Result<()> {
+        require!(season_score.season_id == self.season_id, TriadProtocolError::InvalidSeason);
+
+        if ts < self.start_ts || ts >= self.end_ts || !self.is_eligible(market_id) {
+            return Ok(());
+        }
+
+        season_score.volume = season_score.volume.checked_add(volume).unwrap();
+        season_score.pnl = season_score.pnl.checked_add(pnl).unwrap();
+        season_score.score = match self.score_type {
+            SeasonScoreType::Pnl => season_score.pnl,
+            SeasonScoreType::Volume => season_score.volume as i64,
+        };
+
+        self.update_leaderboard(season_score.authority, season_score.score);
+
+        Ok(())
+    }
+
+    /// Keeps the best scores seen so far. A ranked user whose score drops keeps its slot
+    /// until someone outside the board beats it.
+    fn update_leaderboard(&mut self, user: Pubkey, score: i64) {
+        let slot = self.leaderboard
+            .iter()
+            .position(|entry| entry.user == user)
+            .or_else(|| self.leaderboard.iter().position(|entry| entry.user == Pubkey::default()))
+            .or_else(|| {
+                let last = self.leaderboard.len() - 1;
+                (score > self.leaderboard[last].score).then_some(last)
+            });
+
+        if let Some(slot) = slot {
+            self.leaderboard[slot] = SeasonEntry { user, score };
+            self.leaderboard.sort_by_key(|entry| (
+                entry.user == Pubkey::default(),
+                std::cmp::Reverse(entry.score),
+            ));
+        }
+    }
+
+    /// Prize of `rank`, out of the pool as it stood at the first claim
+    pub fn prize(&mut self, rank: usize) -> u64 {
+        if self.claimed_ranks == 0 {
+            self.settled_prize_pool = self.prize_pool;
+        }
+
+        self.settled_prize_pool
+            .checked_mul(self.prize_bps[rank] as u64)
+            .unwrap()
+            .checked_div(10000)
+            .unwrap()
+    }
+
+    pub fn rank(&self, user: &Pubkey) -> Option<usize> {
+        self.leaderboard.iter().position(|entry| entry.user == *user)
+    }
+}
+
+impl SeasonScore {
+    pub const PREFIX_SEED: &'static [u8] = b"season_score";
+
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+}
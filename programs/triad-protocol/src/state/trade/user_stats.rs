@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::{ state::{ Season, SeasonScore }, errors::TriadProtocolError };
+
+/// Running trading stats of a user across the markets of one collateral mint, for leaderboards.
+/// Amounts are given in that mint.
+#[account]
+pub struct UserStats {
+    pub bump: u8,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    /// Collateral traded, opens and closes
+    pub volume: u64,
+    pub fees_paid: u64,
+    /// Sum of the pnl of closed and paid out orders
+    pub realized_pnl: i64,
+    pub wins: u64,
+    pub losses: u64,
+    /// Markets entered while holding no open order in them
+    pub markets_traded: u64,
+    pub orders_opened: u64,
+    pub first_trade_ts: i64,
+    pub last_trade_ts: i64,
+    /// Season joined in this mint, every trade is scored in it until `season_end_ts`
+    pub season: Pubkey,
+    pub season_end_ts: i64,
+    pub padding: [u8; 32],
+}
+
+impl UserStats {
+    pub const PREFIX_SEED: &'static [u8] = b"user_stats";
+
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+
+    pub fn init(&mut self, bump: u8, authority: Pubkey, mint: Pubkey) {
+        self.bump = bump;
+        self.authority = authority;
+        self.mint = mint;
+    }
+
+    pub fn record_open(&mut self, amount: u64, fee: u64, is_new_market: bool, ts: i64) {
+        if self.first_trade_ts == 0 {
+            self.first_trade_ts = ts;
+        }
+
+        if is_new_market {
+            self.markets_traded = self.markets_traded.checked_add(1).unwrap();
+        }
+
+        self.volume = self.volume.checked_add(amount).unwrap();
+        self.fees_paid = self.fees_paid.checked_add(fee).unwrap();
+        self.orders_opened = self.orders_opened.checked_add(1).unwrap();
+        self.last_trade_ts = ts;
+    }
+
+    /// `volume` is 0 for payouts, only closes before resolution trade collateral back
+    pub fn record_close(&mut self, volume: u64, pnl: i64, ts: i64) {
+        self.volume = self.volume.checked_add(volume).unwrap();
+        self.realized_pnl = self.realized_pnl.checked_add(pnl).unwrap();
+
+        if pnl > 0 {
+            self.wins = self.wins.checked_add(1).unwrap();
+        } else if pnl < 0 {
+            self.losses = self.losses.checked_add(1).unwrap();
+        }
+
+        self.last_trade_ts = ts;
+    }
+
+    /// Whether trades must be scored in the joined season
+    pub fn in_season(&self, ts: i64) -> bool {
+        self.season != Pubkey::default() && ts < self.season_end_ts
+    }
+
+    /// Scores a trade in the joined season, which must be provided while it runs so a trader
+    /// cannot leave out the trades that hurt their score
+    pub fn record_season_trade(
+        &self,
+        season: Option<&mut Account<'_, Season>>,
+        season_score: Option<&mut Account<'_, SeasonScore>>,
+        market_id: u64,
+        volume: u64,
+        pnl: i64,
+        ts: i64
+    ) -> Result<()> {
+        if !self.in_season(ts) {
+            return Ok(());
+        }
+
+        match (season, season_score) {
+            (Some(season), Some(season_score)) => {
+                require!(season.key() == self.season, TriadProtocolError::InvalidSeason);
+                require!(
+                    season_score.authority == self.authority,
+                    TriadProtocolError::InvalidSeason
+                );
+
+                season.record_trade(season_score, market_id, volume, pnl, ts)
+            }
+            _ => Err(TriadProtocolError::SeasonRequired.into()),
+        }
+    }
+}
@@ -31,7 +31,13 @@ pub struct Order {
     pub total_shares: u64,
     pub order_type: OrderType,
     pub direction: OrderDirection,
-    pub padding: [u8; 32],
+    /// Price at or below which a keeper may close the order, 0 when unset
+    pub stop_loss_price: u64,
+    /// Price at or above which a keeper may close the order, 0 when unset
+    pub take_profit_price: u64,
+    /// Batch auction window the order was submitted in, 0 for AMM orders
+    pub batch_id: u64,
+    pub padding: [u8; 8],
 }
 
 #[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Default)]
@@ -56,6 +62,8 @@ pub enum OrderStatus {
     Claimed,
     /// Order has been liquidated
     Liquidated,
+    /// Order is waiting for its batch auction to clear
+    Pending,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
@@ -69,10 +77,57 @@ pub enum OrderType {
 pub struct OpenOrderArgs {
     pub amount: u64,
     pub direction: OrderDirection,
+    /// Highest price the order may fill at, required
+    pub max_price: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MintCompleteSetArgs {
+    /// Collateral to deposit, 1 unit mints 1 Hype share and 1 Flop share
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RedeemCompleteSetArgs {
+    pub hype_order_id: u64,
+    pub flop_order_id: u64,
+    /// Shares to burn from each order, 1 Hype share + 1 Flop share redeems 1 unit
+    pub shares: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetOrderTriggersArgs {
+    pub order_id: u64,
+    /// 0 clears the stop-loss
+    pub stop_loss_price: u64,
+    /// 0 clears the take-profit
+    pub take_profit_price: u64,
+}
+
+impl Order {
+    /// Whether the order holds collateral, filled or still waiting for its batch
+    pub fn is_active(&self) -> bool {
+        matches!(self.status, OrderStatus::Open | OrderStatus::Pending)
+    }
+
+    /// Whether `price` has crossed the stop-loss or take-profit of this order
+    pub fn is_triggered(&self, price: u64) -> bool {
+        (self.stop_loss_price > 0 && price <= self.stop_loss_price) ||
+            (self.take_profit_price > 0 && price >= self.take_profit_price)
+    }
 }
 
 impl UserTrade {
     pub const PREFIX_SEED: &'static [u8] = b"user_trade";
 
     pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+
+    /// Collateral committed by the open orders of this user in `market_id` (in TRD)
+    pub fn market_stake(&self, market_id: u64) -> u64 {
+        self.orders
+            .iter()
+            .filter(|order| order.is_active() && order.market_id == market_id)
+            .map(|order| order.total_amount)
+            .sum()
+    }
 }
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::state::OrderDirection;
+
+#[account]
+pub struct VotingMarket {
+    pub bump: u8,
+    pub authority: Pubkey,
+    /// Unique identifier for the proposal
+    pub vote_id: u64,
+    /// The question proposed for the market
+    pub question: [u8; 80],
+    /// Where the outcome will be verified (e.g., an URL)
+    pub resolution_source: [u8; 80],
+    /// Mint $TRD token, used for paid votes and as the market collateral
+    pub mint: Pubkey,
+    pub voting_start: i64,
+    pub voting_end: i64,
+    /// Schedule of the market once promoted
+    pub market_start: i64,
+    pub market_end: i64,
+    pub resolve_ts: i64,
+    /// Total Hype votes
+    pub total_hype: u64,
+    /// Total Flop votes
+    pub total_flop: u64,
+    pub min_votes: u64,
+    /// Market created from this proposal, only set once promoted
+    pub market_id: u64,
+    pub is_promoted: bool,
+    pub padding: [u8; 64],
+}
+
+#[account]
+pub struct VoteRecord {
+    pub bump: u8,
+    pub voting_market: Pubkey,
+    pub voter: Pubkey,
+    pub votes: u64,
+    pub padding: [u8; 16],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateVotingMarketArgs {
+    pub vote_id: u64,
+    pub question: [u8; 80],
+    pub resolution_source: [u8; 80],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VoteArgs {
+    pub direction: OrderDirection,
+    /// Number of votes to cast, the first vote of each voter is free
+    pub votes: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PromoteVotingMarketArgs {
+    pub market_id: u64,
+}
+
+impl VotingMarket {
+    pub const PREFIX_SEED: &'static [u8] = b"voting_market";
+
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+
+    pub const VOTING_PERIOD: i64 = 7 * 24 * 60 * 60;
+    pub const MARKET_START_DELAY: i64 = 24 * 60 * 60;
+    pub const MARKET_PERIOD: i64 = 6 * 24 * 60 * 60;
+    pub const RESOLVE_DELAY: i64 = 24 * 60 * 60;
+
+    pub fn total_votes(&self) -> u64 {
+        self.total_hype.checked_add(self.total_flop).unwrap()
+    }
+}
+
+impl VoteRecord {
+    pub const PREFIX_SEED: &'static [u8] = b"vote";
+
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+}
@@ -15,6 +15,19 @@ pub struct User {
     pub user_trade: Pubkey,
 }
 
+/// Fees earned by a referrer `User` from the trades of the users it referred, one per mint
+#[account]
+pub struct ReferralRewards {
+    pub bump: u8,
+    /// Referrer `User` account, matches the `referral` of the users it referred
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    /// Total credited since creation (in TRD)
+    pub earned: u64,
+    pub claimed: u64,
+    pub padding: [u8; 32],
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateUserArgs {
     pub name: String,
@@ -25,3 +38,9 @@ impl User {
 
     pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
 }
+
+impl ReferralRewards {
+    pub const PREFIX_SEED: &'static [u8] = b"referral_rewards";
+
+    pub const SPACE: usize = 8 + std::mem::size_of::<Self>();
+}
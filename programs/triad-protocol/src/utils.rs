@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TransferChecked;
+use anchor_spl::token_2022::{
+    self,
+    spl_token_2022::{
+        extension::{
+            transfer_fee::TransferFeeConfig,
+            BaseStateWithExtensions,
+            PodStateWithExtensions,
+        },
+        onchain::invoke_transfer_checked,
+        pod::PodMint,
+    },
+};
+
+/// Fee withheld by the Token-2022 transfer-fee extension when moving `amount` of `mint`.
+/// Classic SPL mints and Token-2022 mints without the extension charge nothing.
+pub fn get_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if mint.owner != &token_2022::ID {
+        return Ok(0);
+    }
+
+    let buffer = mint.try_borrow_data()?;
+    let state = PodStateWithExtensions::<PodMint>::unpack(&buffer)?;
+
+    let fee = match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => config.calculate_epoch_fee(Clock::get()?.epoch, amount).unwrap(),
+        Err(_) => 0,
+    };
+
+    Ok(fee)
+}
+
+/// `transfer_checked` that forwards the CPI context's remaining accounts, so mints with a
+/// Token-2022 transfer hook can resolve the hook's extra account metas.
+pub fn transfer_checked_with_hook<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8
+) -> Result<()> {
+    invoke_transfer_checked(
+        ctx.program.key,
+        ctx.accounts.from,
+        ctx.accounts.mint,
+        ctx.accounts.to,
+        ctx.accounts.authority,
+        &ctx.remaining_accounts,
+        amount,
+        decimals,
+        ctx.signer_seeds
+    ).map_err(Into::into)
+}
@@ -17,8 +17,14 @@ import { RpcOptions } from './types'
 import BN from 'bn.js'
 import { SOL_MINT, TRD_DECIMALS, TRD_MINT } from './utils/constants'
 import { accountToMarketV1, encodeString, formatMarket } from './utils/helpers'
-import { getMarketPDA, getUserTradePDA } from './utils/pda/trade'
-import { getUserPDA } from './utils/pda'
+import {
+  getMarketHistoryPDA,
+  getMarketPDA,
+  getSeasonScorePDA,
+  getUserStatsPDA,
+  getUserTradePDA
+} from './utils/pda/trade'
+import { getEventAuthorityPDA, getUserPDA } from './utils/pda'
 import sendVersionedTransaction from './utils/sendVersionedTransaction'
 import sendTransactionWithOptions from './utils/sendTransactionWithOptions'
 import { swap } from './utils/swap'
@@ -92,6 +98,48 @@ export default class Trade {
     return this.program.account.userTrade.fetch(userTradePDA)
   }
 
+  /**
+   * Get the optional accounts a trade must pass: the market history once the market has one,
+   * and the season the user joined while it runs
+   * @param marketPDA - The market address
+   *
+   */
+  async getTradeAccounts(marketPDA: PublicKey) {
+    const marketHistoryPDA = getMarketHistoryPDA(
+      this.program.programId,
+      marketPDA
+    )
+    const userStatsPDA = getUserStatsPDA(
+      this.program.programId,
+      this.provider.publicKey,
+      this.mint
+    )
+
+    const [marketHistory, userStats] = await Promise.all([
+      this.provider.connection.getAccountInfo(marketHistoryPDA),
+      this.program.account.userStats.fetchNullable(userStatsPDA)
+    ])
+
+    const season =
+      userStats &&
+      !userStats.season.equals(PublicKey.default) &&
+      userStats.seasonEndTs.toNumber() > Date.now() / 1000
+        ? userStats.season
+        : null
+
+    return {
+      marketHistory: marketHistory ? marketHistoryPDA : null,
+      season,
+      seasonScore: season
+        ? getSeasonScorePDA(
+            this.program.programId,
+            season,
+            this.provider.publicKey
+          )
+        : null
+    }
+  }
+
   /**
    * Initialize Market
    * @param market id - new markert id - length + 1
@@ -126,6 +174,7 @@ export default class Trade {
    * @param marketId - The ID of the market
    * @param amount - The amount of the order
    * @param direction - The direction of the order
+   * @param maxPrice - The highest price the order may fill at (1 - 999999)
    * @param token - The token to use for the order
    * @param comment - The comment of the order
    *
@@ -133,7 +182,7 @@ export default class Trade {
    *
    */
   async openOrder(
-    { marketId, amount, direction, token }: OpenOrderArgs,
+    { marketId, amount, direction, maxPrice, token }: OpenOrderArgs,
     options?: RpcOptions
   ): Promise<string> {
     const marketPDA = getMarketPDA(this.program.programId, marketId)
@@ -154,9 +203,11 @@ export default class Trade {
       ixs.push(
         await this.program.methods
           .createUserTrade()
-          .accounts({
+          .accountsPartial({
             signer: this.provider.publicKey,
-            user: userPDA
+            user: userPDA,
+            eventAuthority: getEventAuthorityPDA(this.program.programId),
+            program: this.program.programId
           })
           .instruction()
       )
@@ -190,13 +241,17 @@ export default class Trade {
       await this.program.methods
         .openOrder({
           amount: new BN(amountInTRD),
-          direction: direction
+          direction: direction,
+          maxPrice: new BN(maxPrice)
         })
-        .accounts({
+        .accountsPartial({
           signer: this.provider.publicKey,
           market: marketPDA,
           userTrade: userTradePDA,
-          mint: this.mint
+          mint: this.mint,
+          ...(await this.getTradeAccounts(marketPDA)),
+          eventAuthority: getEventAuthorityPDA(this.program.programId),
+          program: this.program.programId
         })
         .instruction()
     )
@@ -214,12 +269,17 @@ export default class Trade {
    * Close Order
    * @param marketId - The ID of the market
    * @param orderId - The ID of the order
+   * @param minPrice - The lowest price the order may close at (1 - 999999)
    *
    * @param options - RPC options
    *
    */
   async closeOrder(
-    { marketId, orderId }: { marketId: number; orderId: number },
+    {
+      marketId,
+      orderId,
+      minPrice
+    }: { marketId: number; orderId: number; minPrice: number },
     options?: RpcOptions
   ): Promise<string> {
     const marketPDA = getMarketPDA(this.program.programId, marketId)
@@ -229,12 +289,17 @@ export default class Trade {
     )
 
     return sendTransactionWithOptions(
-      this.program.methods.closeOrder(new BN(orderId)).accounts({
-        signer: this.provider.publicKey,
-        market: marketPDA,
-        mint: this.mint,
-        userTrade: userTradePDA
-      }),
+      this.program.methods
+        .closeOrder(new BN(orderId), new BN(minPrice))
+        .accountsPartial({
+          signer: this.provider.publicKey,
+          market: marketPDA,
+          mint: this.mint,
+          userTrade: userTradePDA,
+          ...(await this.getTradeAccounts(marketPDA)),
+          eventAuthority: getEventAuthorityPDA(this.program.programId),
+          program: this.program.programId
+        }),
       options
     )
   }
@@ -332,10 +397,12 @@ export default class Trade {
           amount: new BN(amount * 10 ** TRD_DECIMALS),
           direction: direction
         })
-        .accounts({
+        .accountsPartial({
           signer: this.provider.publicKey,
           market: marketPDA,
-          mint: this.mint
+          mint: this.mint,
+          eventAuthority: getEventAuthorityPDA(this.program.programId),
+          program: this.program.programId
         }),
       options
     )
@@ -359,10 +426,12 @@ export default class Trade {
     ixs.push(
       await this.program.methods
         .collectFee()
-        .accounts({
+        .accountsPartial({
           signer: this.provider.publicKey,
           market: marketPDA,
-          mint: this.mint
+          mint: this.mint,
+          eventAuthority: getEventAuthorityPDA(this.program.programId),
+          program: this.program.programId
         })
         .instruction()
     )
@@ -50,6 +50,7 @@ export type OpenOrderArgs = {
   marketId: number
   amount: number
   direction: OrderDirection
+  maxPrice: number
   token: string
   comment?: string
 }
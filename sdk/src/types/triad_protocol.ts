@@ -146,7 +146,6 @@ export type TriadProtocol = {
         },
         {
           name: 'tokenProgram'
-          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
         },
         {
           name: 'associatedTokenProgram'
@@ -155,6 +154,38 @@ export type TriadProtocol = {
         {
           name: 'systemProgram'
           address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
         }
       ]
       args: [
@@ -169,39 +200,88 @@ export type TriadProtocol = {
       ]
     },
     {
-      name: 'claimStakeRewards'
-      discriminator: [107, 91, 233, 196, 211, 47, 218, 21]
+      name: 'advanceNftRewardEpoch'
+      discriminator: [204, 56, 70, 77, 58, 251, 249, 6]
       accounts: [
         {
           name: 'signer'
-          writable: true
           signer: true
         },
         {
-          name: 'verifier'
+          name: 'rewardPool'
+          writable: true
+        },
+        {
+          name: 'collection'
+          relations: ['rewardPool']
+        }
+      ]
+      args: []
+    },
+    {
+      name: 'claimNftRewards'
+      discriminator: [155, 218, 162, 252, 207, 252, 197, 230]
+      accounts: [
+        {
+          name: 'signer'
           writable: true
           signer: true
         },
         {
-          name: 'stakeVault'
+          name: 'rewardPool'
           writable: true
         },
         {
-          name: 'stake'
+          name: 'asset'
+        },
+        {
+          name: 'rewardClaim'
           writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  110,
+                  102,
+                  116,
+                  95,
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  108,
+                  97,
+                  105,
+                  109
+                ]
+              },
+              {
+                kind: 'account'
+                path: 'rewardPool'
+              },
+              {
+                kind: 'account'
+                path: 'asset'
+              }
+            ]
+          }
         },
         {
           name: 'mint'
-          writable: true
         },
         {
-          name: 'fromAta'
+          name: 'rewardPoolAta'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'account'
-                path: 'stakeVault'
+                path: 'rewardPool'
               },
               {
                 kind: 'account'
@@ -252,7 +332,7 @@ export type TriadProtocol = {
           }
         },
         {
-          name: 'toAta'
+          name: 'userAta'
           writable: true
           pda: {
             seeds: [
@@ -310,7 +390,6 @@ export type TriadProtocol = {
         },
         {
           name: 'tokenProgram'
-          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
         },
         {
           name: 'associatedTokenProgram'
@@ -321,21 +400,11 @@ export type TriadProtocol = {
           address: '11111111111111111111111111111111'
         }
       ]
-      args: [
-        {
-          name: 'args'
-          type: {
-            defined: {
-              name: 'claimStakeRewardsArgs'
-            }
-          }
-        }
-      ]
-      returns: 'u64'
+      args: []
     },
     {
-      name: 'closeOrder'
-      discriminator: [90, 103, 209, 28, 7, 63, 168, 4]
+      name: 'claimReferralRewards'
+      discriminator: [23, 112, 76, 162, 157, 106, 203, 246]
       accounts: [
         {
           name: 'signer'
@@ -343,25 +412,57 @@ export type TriadProtocol = {
           signer: true
         },
         {
-          name: 'userTrade'
-          writable: true
+          name: 'user'
         },
         {
-          name: 'market'
+          name: 'referralRewards'
           writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108,
+                  95,
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  115
+                ]
+              },
+              {
+                kind: 'account'
+                path: 'user'
+              },
+              {
+                kind: 'account'
+                path: 'referral_rewards.mint'
+                account: 'referralRewards'
+              }
+            ]
+          }
         },
         {
           name: 'mint'
-          writable: true
         },
         {
-          name: 'userAta'
+          name: 'referralRewardsAta'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'account'
-                path: 'signer'
+                path: 'referralRewards'
               },
               {
                 kind: 'account'
@@ -412,13 +513,13 @@ export type TriadProtocol = {
           }
         },
         {
-          name: 'marketVault'
+          name: 'userAta'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'account'
-                path: 'market'
+                path: 'signer'
               },
               {
                 kind: 'account'
@@ -470,7 +571,6 @@ export type TriadProtocol = {
         },
         {
           name: 'tokenProgram'
-          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
         },
         {
           name: 'associatedTokenProgram'
@@ -481,16 +581,11 @@ export type TriadProtocol = {
           address: '11111111111111111111111111111111'
         }
       ]
-      args: [
-        {
-          name: 'orderId'
-          type: 'u64'
-        }
-      ]
+      args: []
     },
     {
-      name: 'collectFee'
-      discriminator: [60, 173, 247, 103, 4, 93, 130, 48]
+      name: 'claimSeasonPrize'
+      discriminator: [151, 171, 59, 18, 116, 101, 22, 202]
       accounts: [
         {
           name: 'signer'
@@ -498,21 +593,20 @@ export type TriadProtocol = {
           signer: true
         },
         {
-          name: 'market'
+          name: 'season'
           writable: true
         },
         {
           name: 'mint'
-          writable: true
         },
         {
-          name: 'marketAta'
+          name: 'seasonAta'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'account'
-                path: 'market'
+                path: 'season'
               },
               {
                 kind: 'account'
@@ -563,7 +657,7 @@ export type TriadProtocol = {
           }
         },
         {
-          name: 'signerAta'
+          name: 'userAta'
           writable: true
           pda: {
             seeds: [
@@ -621,7 +715,6 @@ export type TriadProtocol = {
         },
         {
           name: 'tokenProgram'
-          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
         },
         {
           name: 'associatedTokenProgram'
@@ -635,8 +728,8 @@ export type TriadProtocol = {
       args: []
     },
     {
-      name: 'createCollection'
-      discriminator: [156, 251, 92, 54, 233, 2, 16, 82]
+      name: 'claimStakeRewards'
+      discriminator: [107, 91, 233, 196, 211, 47, 218, 21]
       accounts: [
         {
           name: 'signer'
@@ -644,33 +737,138 @@ export type TriadProtocol = {
           signer: true
         },
         {
-          name: 'coreCollection'
+          name: 'verifier'
           writable: true
           signer: true
         },
         {
-          name: 'collection'
+          name: 'stakeVault'
+          writable: true
+        },
+        {
+          name: 'stake'
+          writable: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'fromAta'
           writable: true
           pda: {
             seeds: [
               {
-                kind: 'const'
-                value: [99, 111, 108, 108, 101, 99, 116, 105, 111, 110]
+                kind: 'account'
+                path: 'stakeVault'
               },
               {
-                kind: 'arg'
-                path: 'args.symbol'
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
               }
             ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
           }
         },
         {
-          name: 'metaplexProgram'
-          address: 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+          name: 'toAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
         },
         {
           name: 'tokenProgram'
-          address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
         },
         {
           name: 'associatedTokenProgram'
@@ -679,6 +877,38 @@ export type TriadProtocol = {
         {
           name: 'systemProgram'
           address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
         }
       ]
       args: [
@@ -686,125 +916,41 @@ export type TriadProtocol = {
           name: 'args'
           type: {
             defined: {
-              name: 'createCollectionArgs'
+              name: 'claimStakeRewardsArgs'
             }
           }
         }
       ]
+      returns: 'u64'
     },
     {
-      name: 'createUser'
-      discriminator: [108, 227, 130, 130, 252, 109, 75, 218]
+      name: 'clearBatch'
+      discriminator: [194, 215, 191, 209, 220, 20, 81, 102]
       accounts: [
         {
           name: 'signer'
-          writable: true
           signer: true
         },
         {
-          name: 'referral'
+          name: 'market'
           writable: true
+          relations: ['batchAuction', 'marketHistory']
         },
         {
-          name: 'user'
+          name: 'batchAuction'
           writable: true
-          pda: {
-            seeds: [
-              {
-                kind: 'const'
-                value: [117, 115, 101, 114]
-              },
-              {
-                kind: 'account'
-                path: 'signer'
-              }
-            ]
-          }
-        },
-        {
-          name: 'systemProgram'
-          address: '11111111111111111111111111111111'
-        }
-      ]
-      args: [
-        {
-          name: 'args'
-          type: {
-            defined: {
-              name: 'createUserArgs'
-            }
-          }
-        }
-      ]
-    },
-    {
-      name: 'createUserTrade'
-      discriminator: [232, 235, 58, 194, 135, 248, 153, 1]
-      accounts: [
-        {
-          name: 'signer'
-          writable: true
-          signer: true
-        },
-        {
-          name: 'user'
-          writable: true
-        },
-        {
-          name: 'userTrade'
-          writable: true
-          pda: {
-            seeds: [
-              {
-                kind: 'const'
-                value: [117, 115, 101, 114, 95, 116, 114, 97, 100, 101]
-              },
-              {
-                kind: 'account'
-                path: 'signer'
-              }
-            ]
-          }
-        },
-        {
-          name: 'systemProgram'
-          address: '11111111111111111111111111111111'
-        }
-      ]
-      args: []
-    },
-    {
-      name: 'initializeMarket'
-      discriminator: [35, 35, 189, 193, 155, 48, 170, 203]
-      accounts: [
-        {
-          name: 'signer'
-          writable: true
-          signer: true
         },
         {
-          name: 'market'
+          name: 'marketHistory'
+          docs: ['Candle history, required once the market has one']
           writable: true
-          pda: {
-            seeds: [
-              {
-                kind: 'const'
-                value: [109, 97, 114, 107, 101, 116]
-              },
-              {
-                kind: 'arg'
-                path: 'args.market_id'
-              }
-            ]
-          }
+          optional: true
         },
         {
           name: 'mint'
-          writable: true
         },
         {
-          name: 'marketAta'
-          writable: true
+          name: 'marketVault'
           pda: {
             seeds: [
               {
@@ -861,203 +1007,106 @@ export type TriadProtocol = {
         },
         {
           name: 'tokenProgram'
-          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
-        },
-        {
-          name: 'associatedTokenProgram'
-          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
-        },
-        {
-          name: 'systemProgram'
-          address: '11111111111111111111111111111111'
-        }
-      ]
-      args: [
-        {
-          name: 'args'
-          type: {
-            defined: {
-              name: 'initializeMarketArgs'
-            }
-          }
-        }
-      ]
-    },
-    {
-      name: 'mintTicket'
-      discriminator: [159, 167, 223, 60, 138, 6, 23, 29]
-      accounts: [
-        {
-          name: 'signer'
-          writable: true
-          signer: true
         },
         {
-          name: 'nft'
-          writable: true
+          name: 'eventAuthority'
           pda: {
             seeds: [
               {
                 kind: 'const'
-                value: [110, 102, 116]
-              },
-              {
-                kind: 'arg'
-                path: 'args.number'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
               }
             ]
           }
         },
         {
-          name: 'verifier'
-          writable: true
-          signer: true
-        },
+          name: 'program'
+        }
+      ]
+      args: []
+    },
+    {
+      name: 'closeOrder'
+      discriminator: [90, 103, 209, 28, 7, 63, 168, 4]
+      accounts: [
         {
-          name: 'asset'
+          name: 'signer'
           writable: true
           signer: true
         },
         {
-          name: 'nftMint'
+          name: 'userTrade'
           writable: true
         },
         {
-          name: 'collection'
+          name: 'userStats'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'const'
-                value: [99, 111, 108, 108, 101, 99, 116, 105, 111, 110]
+                value: [117, 115, 101, 114, 95, 115, 116, 97, 116, 115]
               },
-              {
-                kind: 'arg'
-                path: 'args.collection_symbol'
-              }
-            ]
-          }
-        },
-        {
-          name: 'coreCollection'
-          writable: true
-        },
-        {
-          name: 'trdMint'
-          writable: true
-        },
-        {
-          name: 'userTrdAta'
-          writable: true
-          pda: {
-            seeds: [
               {
                 kind: 'account'
                 path: 'signer'
               },
               {
                 kind: 'account'
-                path: 'tokenProgram'
-              },
-              {
-                kind: 'account'
-                path: 'trdMint'
+                path: 'market.mint'
+                account: 'marketV2'
               }
             ]
-            program: {
-              kind: 'const'
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
           }
         },
         {
-          name: 'userNftAta'
+          name: 'market'
           writable: true
+          relations: ['marketHistory']
         },
         {
-          name: 'metaplexProgram'
-          address: 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
-        },
-        {
-          name: 'tokenProgram'
-          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
-        },
-        {
-          name: 'associatedTokenProgram'
-          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
-        },
-        {
-          name: 'systemProgram'
-          address: '11111111111111111111111111111111'
-        }
-      ]
-      args: [
-        {
-          name: 'args'
-          type: {
-            defined: {
-              name: 'mintTicketArgs'
-            }
-          }
-        }
-      ]
-    },
-    {
-      name: 'openOrder'
-      discriminator: [206, 88, 88, 143, 38, 136, 50, 224]
-      accounts: [
-        {
-          name: 'signer'
+          name: 'marketHistory'
+          docs: ['Candle history, required once the market has one']
           writable: true
-          signer: true
+          optional: true
         },
         {
-          name: 'userTrade'
+          name: 'season'
+          docs: [
+            'Season joined by the signer, required while it runs, with their score'
+          ]
           writable: true
+          optional: true
         },
         {
-          name: 'market'
+          name: 'seasonScore'
           writable: true
+          optional: true
         },
         {
           name: 'mint'
           writable: true
         },
         {
-          name: 'userFromAta'
+          name: 'userAta'
           writable: true
           pda: {
             seeds: [
@@ -1114,7 +1163,7 @@ export type TriadProtocol = {
           }
         },
         {
-          name: 'marketToAta'
+          name: 'marketVault'
           writable: true
           pda: {
             seeds: [
@@ -1172,7 +1221,6 @@ export type TriadProtocol = {
         },
         {
           name: 'tokenProgram'
-          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
         },
         {
           name: 'associatedTokenProgram'
@@ -1181,22 +1229,290 @@ export type TriadProtocol = {
         {
           name: 'systemProgram'
           address: '11111111111111111111111111111111'
-        }
-      ]
-      args: [
+        },
         {
-          name: 'args'
-          type: {
-            defined: {
-              name: 'openOrderArgs'
-            }
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
           }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'orderId'
+          type: 'u64'
+        },
+        {
+          name: 'minPrice'
+          type: 'u64'
         }
       ]
     },
     {
-      name: 'payoutOrder'
-      discriminator: [247, 233, 158, 228, 63, 32, 236, 113]
+      name: 'closeOrderSol'
+      discriminator: [169, 245, 187, 178, 29, 213, 0, 250]
+      accounts: [
+        {
+          name: 'order'
+          accounts: [
+            {
+              name: 'signer'
+              writable: true
+              signer: true
+            },
+            {
+              name: 'userTrade'
+              writable: true
+            },
+            {
+              name: 'userStats'
+              writable: true
+              pda: {
+                seeds: [
+                  {
+                    kind: 'const'
+                    value: [117, 115, 101, 114, 95, 115, 116, 97, 116, 115]
+                  },
+                  {
+                    kind: 'account'
+                    path: 'signer'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'market.mint'
+                    account: 'marketV2'
+                  }
+                ]
+              }
+            },
+            {
+              name: 'market'
+              writable: true
+              relations: ['marketHistory']
+            },
+            {
+              name: 'marketHistory'
+              docs: ['Candle history, required once the market has one']
+              writable: true
+              optional: true
+            },
+            {
+              name: 'season'
+              docs: [
+                'Season joined by the signer, required while it runs, with their score'
+              ]
+              writable: true
+              optional: true
+            },
+            {
+              name: 'seasonScore'
+              writable: true
+              optional: true
+            },
+            {
+              name: 'mint'
+              writable: true
+            },
+            {
+              name: 'userAta'
+              writable: true
+              pda: {
+                seeds: [
+                  {
+                    kind: 'account'
+                    path: 'signer'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'tokenProgram'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'mint'
+                  }
+                ]
+                program: {
+                  kind: 'const'
+                  value: [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              name: 'marketVault'
+              writable: true
+              pda: {
+                seeds: [
+                  {
+                    kind: 'account'
+                    path: 'market'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'tokenProgram'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'mint'
+                  }
+                ]
+                program: {
+                  kind: 'const'
+                  value: [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              name: 'tokenProgram'
+            },
+            {
+              name: 'associatedTokenProgram'
+              address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+            },
+            {
+              name: 'systemProgram'
+              address: '11111111111111111111111111111111'
+            },
+            {
+              name: 'eventAuthority'
+              pda: {
+                seeds: [
+                  {
+                    kind: 'const'
+                    value: [
+                      95,
+                      95,
+                      101,
+                      118,
+                      101,
+                      110,
+                      116,
+                      95,
+                      97,
+                      117,
+                      116,
+                      104,
+                      111,
+                      114,
+                      105,
+                      116,
+                      121
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              name: 'program'
+            }
+          ]
+        }
+      ]
+      args: [
+        {
+          name: 'orderId'
+          type: 'u64'
+        },
+        {
+          name: 'minPrice'
+          type: 'u64'
+        }
+      ]
+    },
+    {
+      name: 'collectCreatorFee'
+      discriminator: [20, 22, 86, 123, 198, 28, 219, 132]
       accounts: [
         {
           name: 'signer'
@@ -1204,25 +1520,36 @@ export type TriadProtocol = {
           signer: true
         },
         {
-          name: 'userTrade'
+          name: 'market'
           writable: true
         },
         {
-          name: 'market'
-          writable: true
+          name: 'marketBond'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [109, 97, 114, 107, 101, 116, 95, 98, 111, 110, 100]
+              },
+              {
+                kind: 'account'
+                path: 'market'
+              }
+            ]
+          }
         },
         {
           name: 'mint'
           writable: true
         },
         {
-          name: 'userAta'
+          name: 'marketAta'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'account'
-                path: 'signer'
+                path: 'market'
               },
               {
                 kind: 'account'
@@ -1273,13 +1600,13 @@ export type TriadProtocol = {
           }
         },
         {
-          name: 'marketVault'
+          name: 'signerAta'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'account'
-                path: 'market'
+                path: 'signer'
               },
               {
                 kind: 'account'
@@ -1331,7 +1658,6 @@ export type TriadProtocol = {
         },
         {
           name: 'tokenProgram'
-          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
         },
         {
           name: 'associatedTokenProgram'
@@ -1340,122 +1666,51 @@ export type TriadProtocol = {
         {
           name: 'systemProgram'
           address: '11111111111111111111111111111111'
-        }
-      ]
-      args: [
-        {
-          name: 'orderId'
-          type: 'u64'
-        }
-      ]
-    },
-    {
-      name: 'requestWithdrawStake'
-      discriminator: [175, 9, 77, 31, 145, 136, 30, 207]
-      accounts: [
-        {
-          name: 'signer'
-          writable: true
-          signer: true
-        },
-        {
-          name: 'stakeVault'
-          writable: true
-        },
-        {
-          name: 'user'
-          writable: true
-        },
-        {
-          name: 'stake'
-          writable: true
-        },
-        {
-          name: 'mint'
-          writable: true
-        },
-        {
-          name: 'tokenProgram'
-          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
-        },
-        {
-          name: 'systemProgram'
-          address: '11111111111111111111111111111111'
-        }
-      ]
-      args: []
-    },
-    {
-      name: 'resolveMarket'
-      discriminator: [155, 23, 80, 173, 46, 74, 23, 239]
-      accounts: [
-        {
-          name: 'signer'
-          writable: true
-          signer: true
-        },
-        {
-          name: 'market'
-          writable: true
         },
         {
-          name: 'systemProgram'
-          address: '11111111111111111111111111111111'
-        }
-      ]
-      args: [
-        {
-          name: 'winningDirection'
-          type: {
-            defined: {
-              name: 'winningDirection'
-            }
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
           }
-        }
-      ]
-    },
-    {
-      name: 'resolveMarketV1'
-      discriminator: [67, 202, 40, 49, 111, 136, 234, 183]
-      accounts: [
-        {
-          name: 'signer'
-          writable: true
-          signer: true
-        },
-        {
-          name: 'market'
-          writable: true
         },
         {
-          name: 'systemProgram'
-          address: '11111111111111111111111111111111'
-        }
-      ]
-      args: [
-        {
-          name: 'winningDirection'
-          type: {
-            defined: {
-              name: 'winningDirection'
-            }
-          }
+          name: 'program'
         }
       ]
+      args: []
     },
     {
-      name: 'settleOrder'
-      discriminator: [80, 74, 204, 34, 12, 183, 66, 66]
+      name: 'collectFee'
+      discriminator: [60, 173, 247, 103, 4, 93, 130, 48]
       accounts: [
         {
           name: 'signer'
           writable: true
           signer: true
         },
-        {
-          name: 'userTrade'
-          writable: true
-        },
         {
           name: 'market'
           writable: true
@@ -1465,13 +1720,13 @@ export type TriadProtocol = {
           writable: true
         },
         {
-          name: 'userAta'
+          name: 'marketAta'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'account'
-                path: 'signer'
+                path: 'market'
               },
               {
                 kind: 'account'
@@ -1522,13 +1777,13 @@ export type TriadProtocol = {
           }
         },
         {
-          name: 'marketVault'
+          name: 'signerAta'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'account'
-                path: 'market'
+                path: 'signer'
               },
               {
                 kind: 'account'
@@ -1580,7 +1835,6 @@ export type TriadProtocol = {
         },
         {
           name: 'tokenProgram'
-          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
         },
         {
           name: 'associatedTokenProgram'
@@ -1589,18 +1843,45 @@ export type TriadProtocol = {
         {
           name: 'systemProgram'
           address: '11111111111111111111111111111111'
-        }
-      ]
-      args: [
+        },
         {
-          name: 'orderId'
-          type: 'u64'
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
         }
       ]
+      args: []
     },
     {
-      name: 'stakeToken'
-      discriminator: [191, 127, 193, 101, 37, 96, 87, 211]
+      name: 'createBatchAuction'
+      discriminator: [168, 30, 136, 78, 79, 207, 29, 190]
       accounts: [
         {
           name: 'signer'
@@ -1608,166 +1889,124 @@ export type TriadProtocol = {
           signer: true
         },
         {
-          name: 'stakeVault'
+          name: 'market'
+          writable: true
+        },
+        {
+          name: 'batchAuction'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'const'
-                value: [115, 116, 97, 107, 101, 95, 118, 97, 117, 108, 116]
+                value: [
+                  98,
+                  97,
+                  116,
+                  99,
+                  104,
+                  95,
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
               },
               {
-                kind: 'arg'
-                path: 'args.stake_vault'
+                kind: 'account'
+                path: 'market'
               }
             ]
           }
         },
         {
-          name: 'user'
-          writable: true
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
         },
         {
-          name: 'stake'
-          writable: true
+          name: 'eventAuthority'
           pda: {
             seeds: [
               {
                 kind: 'const'
-                value: [115, 116, 97, 107, 101]
-              },
-              {
-                kind: 'account'
-                path: 'signer'
-              },
-              {
-                kind: 'arg'
-                path: 'args.name'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
               }
             ]
           }
         },
         {
-          name: 'mint'
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'createBatchAuctionArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'createCollection'
+      discriminator: [156, 251, 92, 54, 233, 2, 16, 82]
+      accounts: [
+        {
+          name: 'signer'
           writable: true
+          signer: true
         },
         {
-          name: 'fromAta'
+          name: 'coreCollection'
           writable: true
-          pda: {
-            seeds: [
-              {
-                kind: 'account'
-                path: 'signer'
-              },
-              {
-                kind: 'account'
-                path: 'tokenProgram'
-              },
-              {
-                kind: 'account'
-                path: 'mint'
-              }
-            ]
-            program: {
-              kind: 'const'
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
+          signer: true
         },
         {
-          name: 'toAta'
+          name: 'collection'
           writable: true
           pda: {
             seeds: [
               {
-                kind: 'account'
-                path: 'stakeVault'
-              },
-              {
-                kind: 'account'
-                path: 'tokenProgram'
+                kind: 'const'
+                value: [99, 111, 108, 108, 101, 99, 116, 105, 111, 110]
               },
               {
-                kind: 'account'
-                path: 'mint'
+                kind: 'arg'
+                path: 'args.symbol'
               }
             ]
-            program: {
-              kind: 'const'
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
           }
         },
+        {
+          name: 'metaplexProgram'
+          address: 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+        },
         {
           name: 'tokenProgram'
-          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
+          address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
         },
         {
           name: 'associatedTokenProgram'
@@ -1783,35 +2022,15 @@ export type TriadProtocol = {
           name: 'args'
           type: {
             defined: {
-              name: 'stakeTokenArgs'
+              name: 'createCollectionArgs'
             }
           }
         }
       ]
     },
     {
-      name: 'updateStakeBoost'
-      discriminator: [239, 85, 19, 140, 235, 236, 88, 70]
-      accounts: [
-        {
-          name: 'signer'
-          writable: true
-          signer: true
-        },
-        {
-          name: 'stake'
-          writable: true
-        },
-        {
-          name: 'systemProgram'
-          address: '11111111111111111111111111111111'
-        }
-      ]
-      args: []
-    },
-    {
-      name: 'updateStakeVault'
-      discriminator: [84, 171, 100, 153, 126, 215, 229, 68]
+      name: 'createMarket'
+      discriminator: [103, 226, 97, 235, 200, 188, 251, 254]
       accounts: [
         {
           name: 'signer'
@@ -1819,90 +2038,49 @@ export type TriadProtocol = {
           signer: true
         },
         {
-          name: 'stakeVault'
+          name: 'market'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'const'
-                value: [115, 116, 97, 107, 101, 95, 118, 97, 117, 108, 116]
+                value: [109, 97, 114, 107, 101, 116]
               },
               {
                 kind: 'arg'
-                path: 'args.stake_vault'
+                path: 'args.market_id'
               }
             ]
           }
         },
         {
-          name: 'mint'
-          writable: true
-        },
-        {
-          name: 'fromAta'
+          name: 'marketBond'
           writable: true
           pda: {
             seeds: [
               {
-                kind: 'account'
-                path: 'signer'
-              },
-              {
-                kind: 'account'
-                path: 'tokenProgram'
+                kind: 'const'
+                value: [109, 97, 114, 107, 101, 116, 95, 98, 111, 110, 100]
               },
               {
                 kind: 'account'
-                path: 'mint'
+                path: 'market'
               }
             ]
-            program: {
-              kind: 'const'
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
           }
         },
         {
-          name: 'toAta'
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'userFromAta'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'account'
-                path: 'stakeVault'
+                path: 'signer'
               },
               {
                 kind: 'account'
@@ -1953,66 +2131,13 @@ export type TriadProtocol = {
           }
         },
         {
-          name: 'tokenProgram'
-          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
-        },
-        {
-          name: 'associatedTokenProgram'
-          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
-        },
-        {
-          name: 'systemProgram'
-          address: '11111111111111111111111111111111'
-        }
-      ]
-      args: [
-        {
-          name: 'args'
-          type: {
-            defined: {
-              name: 'updateStakeVaultArgs'
-            }
-          }
-        }
-      ]
-    },
-    {
-      name: 'withdrawStake'
-      discriminator: [153, 8, 22, 138, 105, 176, 87, 66]
-      accounts: [
-        {
-          name: 'signer'
-          writable: true
-          signer: true
-        },
-        {
-          name: 'stakeVault'
-          writable: true
-        },
-        {
-          name: 'user'
-          writable: true
-        },
-        {
-          name: 'stake'
-          writable: true
-        },
-        {
-          name: 'admin'
-          writable: true
-        },
-        {
-          name: 'mint'
-          writable: true
-        },
-        {
-          name: 'fromAta'
+          name: 'marketAta'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'account'
-                path: 'stakeVault'
+                path: 'market'
               },
               {
                 kind: 'account'
@@ -2063,13 +2188,13 @@ export type TriadProtocol = {
           }
         },
         {
-          name: 'toAta'
+          name: 'bondAta'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'account'
-                path: 'signer'
+                path: 'marketBond'
               },
               {
                 kind: 'account'
@@ -2121,7 +2246,6 @@ export type TriadProtocol = {
         },
         {
           name: 'tokenProgram'
-          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
         },
         {
           name: 'associatedTokenProgram'
@@ -2130,13 +2254,54 @@ export type TriadProtocol = {
         {
           name: 'systemProgram'
           address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'createMarketArgs'
+            }
+          }
         }
       ]
-      args: []
     },
     {
-      name: 'withdrawV1'
-      discriminator: [212, 118, 210, 5, 187, 1, 117, 222]
+      name: 'createMarketHistory'
+      discriminator: [160, 6, 193, 142, 206, 127, 198, 130]
       accounts: [
         {
           name: 'signer'
@@ -2144,619 +2309,9242 @@ export type TriadProtocol = {
           signer: true
         },
         {
-          name: 'vault'
+          name: 'market'
           writable: true
         },
         {
-          name: 'userPosition'
+          name: 'marketHistory'
           writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  109,
+                  97,
+                  114,
+                  107,
+                  101,
+                  116,
+                  95,
+                  104,
+                  105,
+                  115,
+                  116,
+                  111,
+                  114,
+                  121
+                ]
+              },
+              {
+                kind: 'account'
+                path: 'market'
+              }
+            ]
+          }
         },
         {
-          name: 'vaultTokenAccount'
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'createMarketHistoryArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'createMarketMetadata'
+      discriminator: [132, 165, 11, 180, 229, 22, 75, 228]
+      accounts: [
+        {
+          name: 'signer'
           writable: true
+          signer: true
         },
         {
-          name: 'userTokenAccount'
+          name: 'market'
           writable: true
         },
         {
-          name: 'associatedTokenProgram'
-          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+          name: 'marketBond'
+          docs: ['Only required when the market creator signs']
+          optional: true
+        },
+        {
+          name: 'marketMetadata'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  109,
+                  97,
+                  114,
+                  107,
+                  101,
+                  116,
+                  95,
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                kind: 'account'
+                path: 'market'
+              }
+            ]
+          }
         },
         {
           name: 'systemProgram'
           address: '11111111111111111111111111111111'
         },
         {
-          name: 'tokenProgram'
-          address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
         }
       ]
       args: [
         {
-          name: 'positionIndex'
-          type: 'u8'
+          name: 'args'
+          type: {
+            defined: {
+              name: 'marketMetadataArgs'
+            }
+          }
         }
       ]
-    }
-  ]
-  accounts: [
-    {
-      name: 'baseCollectionV1'
-      discriminator: [0, 0, 0, 0, 0, 0, 0, 0]
-    },
-    {
-      name: 'collection'
-      discriminator: [48, 160, 232, 205, 191, 207, 26, 141]
-    },
-    {
-      name: 'market'
-      discriminator: [219, 190, 213, 55, 0, 227, 198, 154]
-    },
-    {
-      name: 'marketV2'
-      discriminator: [27, 60, 50, 75, 191, 193, 86, 227]
-    },
-    {
-      name: 'nft'
-      discriminator: [88, 10, 146, 176, 101, 11, 40, 217]
-    },
-    {
-      name: 'stakeV2'
-      discriminator: [207, 98, 130, 13, 118, 181, 238, 47]
-    },
-    {
-      name: 'stakeVault'
-      discriminator: [192, 112, 65, 125, 129, 151, 173, 226]
-    },
-    {
-      name: 'user'
-      discriminator: [159, 117, 95, 227, 239, 151, 58, 236]
     },
     {
-      name: 'userPosition'
-      discriminator: [251, 248, 209, 245, 83, 234, 17, 27]
+      name: 'createNftRewardPool'
+      discriminator: [9, 240, 240, 154, 60, 15, 118, 188]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'collection'
+        },
+        {
+          name: 'coreCollection'
+        },
+        {
+          name: 'rewardPool'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  110,
+                  102,
+                  116,
+                  95,
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                kind: 'account'
+                path: 'collection'
+              }
+            ]
+          }
+        },
+        {
+          name: 'mint'
+        },
+        {
+          name: 'rewardPoolAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'rewardPool'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'createNftRewardPoolArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'createReferralRewards'
+      discriminator: [33, 172, 11, 77, 157, 57, 67, 163]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'user'
+        },
+        {
+          name: 'referralRewards'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108,
+                  95,
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  115
+                ]
+              },
+              {
+                kind: 'account'
+                path: 'user'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+          }
+        },
+        {
+          name: 'mint'
+        },
+        {
+          name: 'referralRewardsAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'referralRewards'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: []
+    },
+    {
+      name: 'createSeason'
+      discriminator: [38, 108, 29, 127, 60, 126, 101, 3]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'season'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [115, 101, 97, 115, 111, 110]
+              },
+              {
+                kind: 'arg'
+                path: 'args.season_id'
+              }
+            ]
+          }
+        },
+        {
+          name: 'mint'
+        },
+        {
+          name: 'seasonAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'season'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'createSeasonArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'createUser'
+      discriminator: [108, 227, 130, 130, 252, 109, 75, 218]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'referral'
+          writable: true
+        },
+        {
+          name: 'user'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [117, 115, 101, 114]
+              },
+              {
+                kind: 'account'
+                path: 'signer'
+              }
+            ]
+          }
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'createUserArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'createUserTrade'
+      discriminator: [232, 235, 58, 194, 135, 248, 153, 1]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'user'
+          writable: true
+        },
+        {
+          name: 'userTrade'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [117, 115, 101, 114, 95, 116, 114, 97, 100, 101]
+              },
+              {
+                kind: 'account'
+                path: 'signer'
+              }
+            ]
+          }
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: []
+    },
+    {
+      name: 'createVotingMarket'
+      discriminator: [248, 43, 128, 81, 198, 62, 122, 148]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'votingMarket'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  118,
+                  111,
+                  116,
+                  105,
+                  110,
+                  103,
+                  95,
+                  109,
+                  97,
+                  114,
+                  107,
+                  101,
+                  116
+                ]
+              },
+              {
+                kind: 'arg'
+                path: 'args.vote_id'
+              }
+            ]
+          }
+        },
+        {
+          name: 'mint'
+        },
+        {
+          name: 'admin'
+          writable: true
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'createVotingMarketArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'executeOrderTrigger'
+      discriminator: [235, 206, 14, 70, 5, 192, 142, 117]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'authority'
+        },
+        {
+          name: 'userTrade'
+          writable: true
+        },
+        {
+          name: 'userStats'
+          docs: ['Trading stats of the order owner']
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [117, 115, 101, 114, 95, 115, 116, 97, 116, 115]
+              },
+              {
+                kind: 'account'
+                path: 'authority'
+              },
+              {
+                kind: 'account'
+                path: 'market.mint'
+                account: 'marketV2'
+              }
+            ]
+          }
+        },
+        {
+          name: 'market'
+          writable: true
+          relations: ['marketHistory']
+        },
+        {
+          name: 'marketHistory'
+          docs: ['Candle history, required once the market has one']
+          writable: true
+          optional: true
+        },
+        {
+          name: 'season'
+          docs: [
+            'Season joined by the order owner, required while it runs, with their score'
+          ]
+          writable: true
+          optional: true
+        },
+        {
+          name: 'seasonScore'
+          writable: true
+          optional: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'userAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'authority'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'keeperAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'marketVault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'orderId'
+          type: 'u64'
+        }
+      ]
+    },
+    {
+      name: 'fundNftRewardPool'
+      discriminator: [215, 159, 207, 99, 199, 110, 61, 85]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'market'
+          writable: true
+        },
+        {
+          name: 'rewardPool'
+          writable: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'marketAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'rewardPoolAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'rewardPool'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: []
+    },
+    {
+      name: 'fundSeason'
+      discriminator: [251, 186, 193, 242, 15, 144, 254, 67]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'season'
+          writable: true
+        },
+        {
+          name: 'mint'
+        },
+        {
+          name: 'userFromAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'seasonAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'season'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'fundSeasonArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'initializeMarket'
+      discriminator: [35, 35, 189, 193, 155, 48, 170, 203]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'market'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [109, 97, 114, 107, 101, 116]
+              },
+              {
+                kind: 'arg'
+                path: 'args.market_id'
+              }
+            ]
+          }
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'marketAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'initializeMarketArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'joinSeason'
+      discriminator: [36, 202, 202, 158, 82, 34, 248, 231]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'season'
+        },
+        {
+          name: 'seasonScore'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [115, 101, 97, 115, 111, 110, 95, 115, 99, 111, 114, 101]
+              },
+              {
+                kind: 'account'
+                path: 'season'
+              },
+              {
+                kind: 'account'
+                path: 'signer'
+              }
+            ]
+          }
+        },
+        {
+          name: 'userStats'
+          docs: [
+            'Stats of the signer in the season mint, every later trade in it must be scored'
+          ]
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [117, 115, 101, 114, 95, 115, 116, 97, 116, 115]
+              },
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'season.mint'
+                account: 'season'
+              }
+            ]
+          }
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: []
+    },
+    {
+      name: 'mintCompleteSet'
+      discriminator: [70, 222, 130, 148, 234, 103, 137, 61]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'userTrade'
+          writable: true
+        },
+        {
+          name: 'market'
+          writable: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'userFromAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'marketToAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'mintCompleteSetArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'mintTicket'
+      discriminator: [159, 167, 223, 60, 138, 6, 23, 29]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'nft'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [110, 102, 116]
+              },
+              {
+                kind: 'arg'
+                path: 'args.number'
+              }
+            ]
+          }
+        },
+        {
+          name: 'verifier'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'asset'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'nftMint'
+          writable: true
+        },
+        {
+          name: 'collection'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 108, 108, 101, 99, 116, 105, 111, 110]
+              },
+              {
+                kind: 'arg'
+                path: 'args.collection_symbol'
+              }
+            ]
+          }
+        },
+        {
+          name: 'coreCollection'
+          writable: true
+        },
+        {
+          name: 'rewardPool'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  110,
+                  102,
+                  116,
+                  95,
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                kind: 'account'
+                path: 'collection'
+              }
+            ]
+          }
+        },
+        {
+          name: 'rewardClaim'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  110,
+                  102,
+                  116,
+                  95,
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  108,
+                  97,
+                  105,
+                  109
+                ]
+              },
+              {
+                kind: 'account'
+                path: 'rewardPool'
+              },
+              {
+                kind: 'account'
+                path: 'asset'
+              }
+            ]
+          }
+        },
+        {
+          name: 'trdMint'
+          writable: true
+        },
+        {
+          name: 'userTrdAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'trdMint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'userNftAta'
+          writable: true
+        },
+        {
+          name: 'metaplexProgram'
+          address: 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+        },
+        {
+          name: 'tokenProgram'
+          address: 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'mintTicketArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'observePrice'
+      discriminator: [147, 178, 4, 21, 14, 213, 81, 159]
+      accounts: [
+        {
+          name: 'market'
+        }
+      ]
+      args: []
+      returns: {
+        defined: {
+          name: 'priceObservation'
+        }
+      }
+    },
+    {
+      name: 'openOrder'
+      discriminator: [206, 88, 88, 143, 38, 136, 50, 224]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'userTrade'
+          writable: true
+        },
+        {
+          name: 'userStats'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [117, 115, 101, 114, 95, 115, 116, 97, 116, 115]
+              },
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'market.mint'
+                account: 'marketV2'
+              }
+            ]
+          }
+        },
+        {
+          name: 'market'
+          writable: true
+          relations: ['marketHistory']
+        },
+        {
+          name: 'marketHistory'
+          docs: ['Candle history, required once the market has one']
+          writable: true
+          optional: true
+        },
+        {
+          name: 'season'
+          docs: [
+            'Season joined by the signer, required while it runs, with their score'
+          ]
+          writable: true
+          optional: true
+        },
+        {
+          name: 'seasonScore'
+          writable: true
+          optional: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'userFromAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'marketToAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'user'
+          docs: [
+            'Trader\'s `User`, with the two accounts below credits its referrer when provided'
+          ]
+          optional: true
+        },
+        {
+          name: 'referralRewards'
+          writable: true
+          optional: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108,
+                  95,
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100,
+                  115
+                ]
+              },
+              {
+                kind: 'account'
+                path: 'referral_rewards.user'
+                account: 'referralRewards'
+              },
+              {
+                kind: 'account'
+                path: 'market.mint'
+                account: 'marketV2'
+              }
+            ]
+          }
+        },
+        {
+          name: 'referralRewardsAta'
+          writable: true
+          optional: true
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'openOrderArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'openOrderSol'
+      discriminator: [153, 15, 116, 70, 247, 239, 185, 213]
+      accounts: [
+        {
+          name: 'order'
+          accounts: [
+            {
+              name: 'signer'
+              writable: true
+              signer: true
+            },
+            {
+              name: 'userTrade'
+              writable: true
+            },
+            {
+              name: 'userStats'
+              writable: true
+              pda: {
+                seeds: [
+                  {
+                    kind: 'const'
+                    value: [117, 115, 101, 114, 95, 115, 116, 97, 116, 115]
+                  },
+                  {
+                    kind: 'account'
+                    path: 'signer'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'market.mint'
+                    account: 'marketV2'
+                  }
+                ]
+              }
+            },
+            {
+              name: 'market'
+              writable: true
+              relations: ['marketHistory']
+            },
+            {
+              name: 'marketHistory'
+              docs: ['Candle history, required once the market has one']
+              writable: true
+              optional: true
+            },
+            {
+              name: 'season'
+              docs: [
+                'Season joined by the signer, required while it runs, with their score'
+              ]
+              writable: true
+              optional: true
+            },
+            {
+              name: 'seasonScore'
+              writable: true
+              optional: true
+            },
+            {
+              name: 'mint'
+              writable: true
+            },
+            {
+              name: 'userFromAta'
+              writable: true
+              pda: {
+                seeds: [
+                  {
+                    kind: 'account'
+                    path: 'signer'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'tokenProgram'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'mint'
+                  }
+                ]
+                program: {
+                  kind: 'const'
+                  value: [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              name: 'marketToAta'
+              writable: true
+              pda: {
+                seeds: [
+                  {
+                    kind: 'account'
+                    path: 'market'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'tokenProgram'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'mint'
+                  }
+                ]
+                program: {
+                  kind: 'const'
+                  value: [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              name: 'user'
+              docs: [
+                'Trader\'s `User`, with the two accounts below credits its referrer when provided'
+              ]
+              optional: true
+            },
+            {
+              name: 'referralRewards'
+              writable: true
+              optional: true
+              pda: {
+                seeds: [
+                  {
+                    kind: 'const'
+                    value: [
+                      114,
+                      101,
+                      102,
+                      101,
+                      114,
+                      114,
+                      97,
+                      108,
+                      95,
+                      114,
+                      101,
+                      119,
+                      97,
+                      114,
+                      100,
+                      115
+                    ]
+                  },
+                  {
+                    kind: 'account'
+                    path: 'referral_rewards.user'
+                    account: 'referralRewards'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'market.mint'
+                    account: 'marketV2'
+                  }
+                ]
+              }
+            },
+            {
+              name: 'referralRewardsAta'
+              writable: true
+              optional: true
+            },
+            {
+              name: 'tokenProgram'
+            },
+            {
+              name: 'associatedTokenProgram'
+              address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+            },
+            {
+              name: 'systemProgram'
+              address: '11111111111111111111111111111111'
+            },
+            {
+              name: 'eventAuthority'
+              pda: {
+                seeds: [
+                  {
+                    kind: 'const'
+                    value: [
+                      95,
+                      95,
+                      101,
+                      118,
+                      101,
+                      110,
+                      116,
+                      95,
+                      97,
+                      117,
+                      116,
+                      104,
+                      111,
+                      114,
+                      105,
+                      116,
+                      121
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              name: 'program'
+            }
+          ]
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'openOrderArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'payoutAll'
+      discriminator: [19, 172, 68, 187, 151, 228, 34, 159]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'userTrade'
+          writable: true
+        },
+        {
+          name: 'userStats'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [117, 115, 101, 114, 95, 115, 116, 97, 116, 115]
+              },
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'market.mint'
+                account: 'marketV2'
+              }
+            ]
+          }
+        },
+        {
+          name: 'season'
+          docs: [
+            'Season joined by the signer, required while it runs, with their score'
+          ]
+          writable: true
+          optional: true
+        },
+        {
+          name: 'seasonScore'
+          writable: true
+          optional: true
+        },
+        {
+          name: 'market'
+          writable: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'userAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'marketVault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: []
+    },
+    {
+      name: 'payoutOrder'
+      discriminator: [247, 233, 158, 228, 63, 32, 236, 113]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'userTrade'
+          writable: true
+        },
+        {
+          name: 'userStats'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [117, 115, 101, 114, 95, 115, 116, 97, 116, 115]
+              },
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'market.mint'
+                account: 'marketV2'
+              }
+            ]
+          }
+        },
+        {
+          name: 'market'
+          writable: true
+        },
+        {
+          name: 'season'
+          docs: [
+            'Season joined by the signer, required while it runs, with their score'
+          ]
+          writable: true
+          optional: true
+        },
+        {
+          name: 'seasonScore'
+          writable: true
+          optional: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'userAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'marketVault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'orderId'
+          type: 'u64'
+        }
+      ]
+    },
+    {
+      name: 'payoutOrderSol'
+      discriminator: [161, 15, 109, 83, 232, 153, 194, 17]
+      accounts: [
+        {
+          name: 'order'
+          accounts: [
+            {
+              name: 'signer'
+              writable: true
+              signer: true
+            },
+            {
+              name: 'userTrade'
+              writable: true
+            },
+            {
+              name: 'userStats'
+              writable: true
+              pda: {
+                seeds: [
+                  {
+                    kind: 'const'
+                    value: [117, 115, 101, 114, 95, 115, 116, 97, 116, 115]
+                  },
+                  {
+                    kind: 'account'
+                    path: 'signer'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'market.mint'
+                    account: 'marketV2'
+                  }
+                ]
+              }
+            },
+            {
+              name: 'market'
+              writable: true
+            },
+            {
+              name: 'season'
+              docs: [
+                'Season joined by the signer, required while it runs, with their score'
+              ]
+              writable: true
+              optional: true
+            },
+            {
+              name: 'seasonScore'
+              writable: true
+              optional: true
+            },
+            {
+              name: 'mint'
+              writable: true
+            },
+            {
+              name: 'userAta'
+              writable: true
+              pda: {
+                seeds: [
+                  {
+                    kind: 'account'
+                    path: 'signer'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'tokenProgram'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'mint'
+                  }
+                ]
+                program: {
+                  kind: 'const'
+                  value: [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              name: 'marketVault'
+              writable: true
+              pda: {
+                seeds: [
+                  {
+                    kind: 'account'
+                    path: 'market'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'tokenProgram'
+                  },
+                  {
+                    kind: 'account'
+                    path: 'mint'
+                  }
+                ]
+                program: {
+                  kind: 'const'
+                  value: [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              name: 'tokenProgram'
+            },
+            {
+              name: 'associatedTokenProgram'
+              address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+            },
+            {
+              name: 'systemProgram'
+              address: '11111111111111111111111111111111'
+            },
+            {
+              name: 'eventAuthority'
+              pda: {
+                seeds: [
+                  {
+                    kind: 'const'
+                    value: [
+                      95,
+                      95,
+                      101,
+                      118,
+                      101,
+                      110,
+                      116,
+                      95,
+                      97,
+                      117,
+                      116,
+                      104,
+                      111,
+                      114,
+                      105,
+                      116,
+                      121
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              name: 'program'
+            }
+          ]
+        }
+      ]
+      args: [
+        {
+          name: 'orderId'
+          type: 'u64'
+        }
+      ]
+    },
+    {
+      name: 'payoutOrders'
+      discriminator: [198, 215, 63, 161, 83, 217, 102, 66]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'market'
+          writable: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'keeperAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'marketVault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: []
+    },
+    {
+      name: 'promoteVotingMarket'
+      discriminator: [112, 205, 253, 234, 167, 90, 194, 110]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'votingMarket'
+          writable: true
+        },
+        {
+          name: 'market'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [109, 97, 114, 107, 101, 116]
+              },
+              {
+                kind: 'arg'
+                path: 'args.market_id'
+              }
+            ]
+          }
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'marketAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'promoteVotingMarketArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'redeemCompleteSet'
+      discriminator: [73, 220, 176, 168, 65, 16, 70, 239]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'userTrade'
+          writable: true
+        },
+        {
+          name: 'market'
+          writable: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'userAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'marketVault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'redeemCompleteSetArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'requestWithdrawStake'
+      discriminator: [175, 9, 77, 31, 145, 136, 30, 207]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'stakeVault'
+          writable: true
+        },
+        {
+          name: 'user'
+          writable: true
+        },
+        {
+          name: 'stake'
+          writable: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        }
+      ]
+      args: []
+    },
+    {
+      name: 'resolveMarket'
+      discriminator: [155, 23, 80, 173, 46, 74, 23, 239]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'market'
+          writable: true
+          relations: ['batchAuction']
+        },
+        {
+          name: 'marketBond'
+          docs: [
+            'Only required when the resolver named by the market creator signs'
+          ]
+          optional: true
+        },
+        {
+          name: 'batchAuction'
+          docs: [
+            'Required for batch auction markets, which settle only once their last batch is cleared'
+          ]
+          optional: true
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'winningDirection'
+          type: {
+            defined: {
+              name: 'winningDirection'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'resolveMarketV1'
+      discriminator: [67, 202, 40, 49, 111, 136, 234, 183]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'market'
+          writable: true
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        }
+      ]
+      args: [
+        {
+          name: 'winningDirection'
+          type: {
+            defined: {
+              name: 'winningDirection'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'setOrderTriggers'
+      discriminator: [132, 92, 10, 20, 11, 139, 176, 32]
+      accounts: [
+        {
+          name: 'signer'
+          signer: true
+        },
+        {
+          name: 'userTrade'
+          writable: true
+        },
+        {
+          name: 'market'
+          writable: true
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'setOrderTriggersArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'settleMarketBond'
+      discriminator: [216, 8, 64, 97, 168, 38, 71, 158]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'market'
+          writable: true
+        },
+        {
+          name: 'marketBond'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [109, 97, 114, 107, 101, 116, 95, 98, 111, 110, 100]
+              },
+              {
+                kind: 'account'
+                path: 'market'
+              }
+            ]
+          }
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'bondAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'marketBond'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'marketVault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'creator'
+        },
+        {
+          name: 'creatorAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'creator'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'admin'
+        },
+        {
+          name: 'adminAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'admin'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: []
+    },
+    {
+      name: 'settleOrder'
+      discriminator: [80, 74, 204, 34, 12, 183, 66, 66]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'userTrade'
+          writable: true
+        },
+        {
+          name: 'market'
+          writable: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'userAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'marketVault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'orderId'
+          type: 'u64'
+        }
+      ]
+    },
+    {
+      name: 'stakeToken'
+      discriminator: [191, 127, 193, 101, 37, 96, 87, 211]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'stakeVault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [115, 116, 97, 107, 101, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'arg'
+                path: 'args.stake_vault'
+              }
+            ]
+          }
+        },
+        {
+          name: 'user'
+          writable: true
+        },
+        {
+          name: 'stake'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [115, 116, 97, 107, 101]
+              },
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'arg'
+                path: 'args.name'
+              }
+            ]
+          }
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'fromAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'toAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'stakeVault'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'stakeTokenArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'submitBatchOrder'
+      discriminator: [35, 173, 49, 40, 36, 49, 158, 224]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'userTrade'
+          writable: true
+        },
+        {
+          name: 'userStats'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [117, 115, 101, 114, 95, 115, 116, 97, 116, 115]
+              },
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'market.mint'
+                account: 'marketV2'
+              }
+            ]
+          }
+        },
+        {
+          name: 'season'
+          docs: [
+            'Season joined by the signer, required while it runs, with their score'
+          ]
+          writable: true
+          optional: true
+        },
+        {
+          name: 'seasonScore'
+          writable: true
+          optional: true
+        },
+        {
+          name: 'market'
+          writable: true
+          relations: ['batchAuction']
+        },
+        {
+          name: 'batchAuction'
+          writable: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'userFromAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'marketToAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'submitBatchOrderArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'updateMarketLimits'
+      discriminator: [157, 95, 9, 167, 249, 57, 142, 129]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'market'
+          writable: true
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'updateMarketLimitsArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'updateMarketMetadata'
+      discriminator: [28, 237, 190, 65, 132, 24, 133, 243]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'market'
+          writable: true
+          relations: ['marketMetadata']
+        },
+        {
+          name: 'marketBond'
+          docs: ['Only required when the market creator signs']
+          optional: true
+        },
+        {
+          name: 'marketMetadata'
+          writable: true
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'marketMetadataArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'updateStakeBoost'
+      discriminator: [239, 85, 19, 140, 235, 236, 88, 70]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'stake'
+          writable: true
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        }
+      ]
+      args: []
+    },
+    {
+      name: 'updateStakeVault'
+      discriminator: [84, 171, 100, 153, 126, 215, 229, 68]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'stakeVault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [115, 116, 97, 107, 101, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'arg'
+                path: 'args.stake_vault'
+              }
+            ]
+          }
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'fromAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'toAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'stakeVault'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'updateStakeVaultArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'verifyMarket'
+      discriminator: [117, 131, 234, 199, 26, 123, 63, 62]
+      accounts: [
+        {
+          name: 'signer'
+          signer: true
+        },
+        {
+          name: 'market'
+        },
+        {
+          name: 'mint'
+        },
+        {
+          name: 'marketVault'
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'market'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        }
+      ]
+      args: []
+      returns: {
+        defined: {
+          name: 'solvencyReport'
+        }
+      }
+    },
+    {
+      name: 'voidMarket'
+      discriminator: [243, 175, 46, 124, 95, 101, 39, 69]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'market'
+          writable: true
+          relations: ['batchAuction']
+        },
+        {
+          name: 'batchAuction'
+          docs: [
+            'Required for batch auction markets, which settle only once their last batch is cleared'
+          ]
+          optional: true
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: []
+    },
+    {
+      name: 'vote'
+      discriminator: [227, 110, 155, 23, 136, 126, 172, 25]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'votingMarket'
+          writable: true
+        },
+        {
+          name: 'voteRecord'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [118, 111, 116, 101]
+              },
+              {
+                kind: 'account'
+                path: 'votingMarket'
+              },
+              {
+                kind: 'account'
+                path: 'signer'
+              }
+            ]
+          }
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'userFromAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'admin'
+        },
+        {
+          name: 'adminAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'admin'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          name: 'program'
+        }
+      ]
+      args: [
+        {
+          name: 'args'
+          type: {
+            defined: {
+              name: 'voteArgs'
+            }
+          }
+        }
+      ]
+    },
+    {
+      name: 'withdrawStake'
+      discriminator: [153, 8, 22, 138, 105, 176, 87, 66]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'stakeVault'
+          writable: true
+        },
+        {
+          name: 'user'
+          writable: true
+        },
+        {
+          name: 'stake'
+          writable: true
+        },
+        {
+          name: 'admin'
+          writable: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'fromAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'stakeVault'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'toAta'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'account'
+                path: 'signer'
+              },
+              {
+                kind: 'account'
+                path: 'tokenProgram'
+              },
+              {
+                kind: 'account'
+                path: 'mint'
+              }
+            ]
+            program: {
+              kind: 'const'
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        }
+      ]
+      args: []
+    },
+    {
+      name: 'withdrawV1'
+      discriminator: [212, 118, 210, 5, 187, 1, 117, 222]
+      accounts: [
+        {
+          name: 'signer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'vault'
+          writable: true
+        },
+        {
+          name: 'userPosition'
+          writable: true
+        },
+        {
+          name: 'vaultTokenAccount'
+          writable: true
+        },
+        {
+          name: 'userTokenAccount'
+          writable: true
+        },
+        {
+          name: 'associatedTokenProgram'
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'tokenProgram'
+          address: 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+        }
+      ]
+      args: [
+        {
+          name: 'positionIndex'
+          type: 'u8'
+        }
+      ]
+    }
+  ]
+  accounts: [
+    {
+      name: 'baseAssetV1'
+      discriminator: [0, 0, 0, 0, 0, 0, 0, 0]
+    },
+    {
+      name: 'baseCollectionV1'
+      discriminator: [0, 0, 0, 0, 0, 0, 0, 0]
+    },
+    {
+      name: 'batchAuction'
+      discriminator: [4, 96, 16, 74, 94, 40, 229, 244]
+    },
+    {
+      name: 'collection'
+      discriminator: [48, 160, 232, 205, 191, 207, 26, 141]
+    },
+    {
+      name: 'market'
+      discriminator: [219, 190, 213, 55, 0, 227, 198, 154]
+    },
+    {
+      name: 'marketBond'
+      discriminator: [191, 236, 88, 110, 189, 161, 118, 126]
+    },
+    {
+      name: 'marketHistory'
+      discriminator: [124, 69, 59, 69, 194, 71, 30, 106]
+    },
+    {
+      name: 'marketMetadata'
+      discriminator: [12, 16, 109, 58, 31, 252, 133, 8]
+    },
+    {
+      name: 'marketV2'
+      discriminator: [27, 60, 50, 75, 191, 193, 86, 227]
+    },
+    {
+      name: 'nft'
+      discriminator: [88, 10, 146, 176, 101, 11, 40, 217]
+    },
+    {
+      name: 'nftRewardClaim'
+      discriminator: [4, 33, 176, 147, 85, 162, 93, 49]
+    },
+    {
+      name: 'nftRewardPool'
+      discriminator: [248, 36, 199, 26, 39, 49, 109, 95]
+    },
+    {
+      name: 'referralRewards'
+      discriminator: [160, 0, 202, 44, 122, 245, 89, 169]
+    },
+    {
+      name: 'season'
+      discriminator: [76, 67, 93, 156, 180, 157, 248, 47]
+    },
+    {
+      name: 'seasonScore'
+      discriminator: [181, 69, 85, 1, 223, 56, 62, 155]
+    },
+    {
+      name: 'stakeV2'
+      discriminator: [207, 98, 130, 13, 118, 181, 238, 47]
+    },
+    {
+      name: 'stakeVault'
+      discriminator: [192, 112, 65, 125, 129, 151, 173, 226]
+    },
+    {
+      name: 'user'
+      discriminator: [159, 117, 95, 227, 239, 151, 58, 236]
+    },
+    {
+      name: 'userPosition'
+      discriminator: [251, 248, 209, 245, 83, 234, 17, 27]
+    },
+    {
+      name: 'userStats'
+      discriminator: [176, 223, 136, 27, 122, 79, 32, 227]
     },
     {
       name: 'userTrade'
       discriminator: [149, 190, 47, 218, 136, 9, 222, 222]
     },
     {
-      name: 'vault'
-      discriminator: [211, 8, 232, 43, 2, 152, 117, 119]
-    }
-  ]
-  events: [
+      name: 'vault'
+      discriminator: [211, 8, 232, 43, 2, 152, 117, 119]
+    },
+    {
+      name: 'voteRecord'
+      discriminator: [112, 9, 123, 165, 234, 9, 157, 167]
+    },
+    {
+      name: 'votingMarket'
+      discriminator: [81, 231, 82, 194, 247, 159, 237, 176]
+    }
+  ]
+  events: [
+    {
+      name: 'batchAuctionUpdate'
+      discriminator: [118, 223, 144, 50, 198, 116, 155, 163]
+    },
+    {
+      name: 'collectionUpdate'
+      discriminator: [146, 88, 146, 164, 109, 122, 68, 197]
+    },
+    {
+      name: 'feeCollected'
+      discriminator: [12, 28, 17, 248, 244, 36, 8, 73]
+    },
+    {
+      name: 'liquidityUpdate'
+      discriminator: [37, 238, 201, 1, 234, 109, 160, 74]
+    },
+    {
+      name: 'marketBondUpdate'
+      discriminator: [52, 73, 173, 85, 212, 43, 249, 182]
+    },
+    {
+      name: 'marketHistoryUpdate'
+      discriminator: [70, 207, 202, 114, 178, 41, 212, 107]
+    },
+    {
+      name: 'marketLimitsUpdate'
+      discriminator: [187, 160, 101, 47, 138, 139, 202, 213]
+    },
+    {
+      name: 'marketMetadataUpdate'
+      discriminator: [68, 237, 19, 204, 35, 56, 6, 115]
+    },
+    {
+      name: 'marketUpdate'
+      discriminator: [170, 101, 124, 32, 249, 253, 251, 96]
+    },
+    {
+      name: 'nftRewardEpoch'
+      discriminator: [40, 27, 239, 43, 15, 167, 236, 140]
+    },
+    {
+      name: 'nftRewardsClaimed'
+      discriminator: [37, 37, 55, 177, 124, 94, 37, 84]
+    },
+    {
+      name: 'orderTriggered'
+      discriminator: [242, 228, 9, 85, 62, 161, 99, 208]
+    },
+    {
+      name: 'orderTriggersUpdate'
+      discriminator: [190, 11, 31, 117, 43, 80, 40, 203]
+    },
+    {
+      name: 'orderUpdate'
+      discriminator: [97, 239, 148, 96, 83, 234, 245, 14]
+    },
+    {
+      name: 'priceUpdate'
+      discriminator: [222, 51, 180, 226, 165, 188, 203, 54]
+    },
+    {
+      name: 'referralFeeUpdate'
+      discriminator: [6, 30, 12, 54, 182, 215, 65, 152]
+    },
+    {
+      name: 'referralRewardsClaimed'
+      discriminator: [178, 107, 76, 169, 252, 154, 45, 235]
+    },
+    {
+      name: 'referralRewardsUpdate'
+      discriminator: [62, 181, 153, 146, 87, 194, 12, 75]
+    },
+    {
+      name: 'seasonJoined'
+      discriminator: [85, 246, 110, 209, 46, 188, 215, 93]
+    },
+    {
+      name: 'seasonPrizeClaimed'
+      discriminator: [135, 199, 106, 45, 156, 215, 207, 199]
+    },
+    {
+      name: 'seasonUpdate'
+      discriminator: [244, 49, 97, 95, 217, 67, 202, 151]
+    },
+    {
+      name: 'solvencyUpdate'
+      discriminator: [114, 50, 95, 120, 144, 219, 119, 68]
+    },
+    {
+      name: 'stakeRewards'
+      discriminator: [236, 217, 227, 239, 6, 129, 188, 218]
+    },
+    {
+      name: 'stakeUpdate'
+      discriminator: [163, 182, 248, 13, 48, 172, 97, 71]
+    },
+    {
+      name: 'stakeVaultUpdate'
+      discriminator: [248, 185, 169, 135, 115, 111, 151, 12]
+    },
+    {
+      name: 'ticketMinted'
+      discriminator: [22, 17, 212, 38, 91, 144, 104, 109]
+    },
+    {
+      name: 'userTradeUpdate'
+      discriminator: [115, 163, 205, 37, 70, 42, 123, 80]
+    },
+    {
+      name: 'userUpdate'
+      discriminator: [186, 172, 62, 170, 233, 110, 205, 156]
+    },
+    {
+      name: 'voteCast'
+      discriminator: [39, 53, 195, 104, 188, 17, 225, 213]
+    },
+    {
+      name: 'votingMarketUpdate'
+      discriminator: [160, 93, 58, 65, 126, 104, 60, 35]
+    }
+  ]
+  errors: [
+    {
+      code: 6000
+      name: 'unauthorized'
+      msg: 'Unauthorized access'
+    },
+    {
+      code: 6001
+      name: 'invalidPosition'
+      msg: 'Invalid position'
+    },
+    {
+      code: 6002
+      name: 'invalidWithdrawAmount'
+      msg: 'Invalid withdraw amount'
+    },
+    {
+      code: 6003
+      name: 'stakeLocked'
+      msg: 'Stake is locked'
+    },
+    {
+      code: 6004
+      name: 'insufficientFunds'
+      msg: 'Insufficient funds'
+    },
+    {
+      code: 6005
+      name: 'noRewardsAvailable'
+      msg: 'No rewards available'
+    },
+    {
+      code: 6006
+      name: 'invalidPrice'
+      msg: 'Invalid price'
+    },
+    {
+      code: 6007
+      name: 'noAvailableOrderSlot'
+      msg: 'No available order slot'
+    },
+    {
+      code: 6008
+      name: 'marketInactive'
+      msg: 'Market is inactive'
+    },
+    {
+      code: 6009
+      name: 'orderNotFound'
+      msg: 'Order not found'
+    },
+    {
+      code: 6010
+      name: 'questionPeriodNotStarted'
+      msg: 'Question period not started'
+    },
+    {
+      code: 6011
+      name: 'questionPeriodEnded'
+      msg: 'Question period ended'
+    },
+    {
+      code: 6012
+      name: 'stakeVaultLocked'
+      msg: 'Stake vault is locked'
+    },
+    {
+      code: 6013
+      name: 'marketStillActive'
+      msg: 'Market still active'
+    },
+    {
+      code: 6014
+      name: 'orderNotOpen'
+      msg: 'Order not open'
+    },
+    {
+      code: 6015
+      name: 'hasOpenedOrders'
+      msg: 'Has opened orders'
+    },
+    {
+      code: 6016
+      name: 'insufficientLiquidity'
+      msg: 'Insufficient liquidity'
+    },
+    {
+      code: 6017
+      name: 'marketNotResolved'
+      msg: 'Market not resolved'
+    },
+    {
+      code: 6018
+      name: 'marketAlreadyResolved'
+      msg: 'Market already resolved'
+    },
+    {
+      code: 6019
+      name: 'concurrentTransaction'
+      msg: 'Concurrent transaction'
+    },
+    {
+      code: 6020
+      name: 'collectionFull'
+      msg: 'Collection is full'
+    },
+    {
+      code: 6021
+      name: 'insufficientShares'
+      msg: 'Insufficient shares'
+    },
+    {
+      code: 6022
+      name: 'invalidOrderDirection'
+      msg: 'Invalid order direction'
+    },
+    {
+      code: 6023
+      name: 'invalidRemainingAccounts'
+      msg: 'Invalid remaining accounts'
+    },
+    {
+      code: 6024
+      name: 'marketInsolvent'
+      msg: 'Market vault does not cover its liabilities'
+    },
+    {
+      code: 6025
+      name: 'votingNotActive'
+      msg: 'Voting is not active'
+    },
+    {
+      code: 6026
+      name: 'votingStillActive'
+      msg: 'Voting still active'
+    },
+    {
+      code: 6027
+      name: 'notEnoughVotes'
+      msg: 'Not enough votes'
+    },
+    {
+      code: 6028
+      name: 'votingMarketAlreadyPromoted'
+      msg: 'Voting market already promoted'
+    },
+    {
+      code: 6029
+      name: 'invalidMarketParameters'
+      msg: 'Invalid market parameters'
+    },
+    {
+      code: 6030
+      name: 'invalidWinningDirection'
+      msg: 'Invalid winning direction'
+    },
+    {
+      code: 6031
+      name: 'bondAlreadySettled'
+      msg: 'Bond already settled'
+    },
+    {
+      code: 6032
+      name: 'userStakeLimitExceeded'
+      msg: 'User stake limit exceeded'
+    },
+    {
+      code: 6033
+      name: 'orderSizeLimitExceeded'
+      msg: 'Order size limit exceeded'
+    },
+    {
+      code: 6034
+      name: 'openInterestLimitExceeded'
+      msg: 'Open interest limit exceeded'
+    },
+    {
+      code: 6035
+      name: 'rewardEpochNotEnded'
+      msg: 'Reward epoch not ended'
+    },
+    {
+      code: 6036
+      name: 'rewardAlreadyClaimed'
+      msg: 'Reward already claimed'
+    },
+    {
+      code: 6037
+      name: 'invalidAsset'
+      msg: 'Invalid asset'
+    },
+    {
+      code: 6038
+      name: 'invalidReferral'
+      msg: 'Invalid referral'
+    },
+    {
+      code: 6039
+      name: 'invalidSeason'
+      msg: 'Invalid season'
+    },
+    {
+      code: 6040
+      name: 'seasonNotEnded'
+      msg: 'Season not ended'
+    },
+    {
+      code: 6041
+      name: 'notRanked'
+      msg: 'Not ranked'
+    },
+    {
+      code: 6042
+      name: 'invalidMarketMetadata'
+      msg: 'Invalid market metadata'
+    },
+    {
+      code: 6043
+      name: 'marketAlreadyStarted'
+      msg: 'Market already started'
+    },
+    {
+      code: 6044
+      name: 'notNativeMarket'
+      msg: 'Market is not SOL-denominated'
+    },
+    {
+      code: 6045
+      name: 'invalidOrderTrigger'
+      msg: 'Invalid order trigger'
+    },
+    {
+      code: 6046
+      name: 'orderTriggerNotReached'
+      msg: 'Order trigger not reached'
+    },
+    {
+      code: 6047
+      name: 'slippageExceeded'
+      msg: 'Slippage exceeded'
+    },
+    {
+      code: 6048
+      name: 'invalidMarketMode'
+      msg: 'Invalid market mode'
+    },
+    {
+      code: 6049
+      name: 'batchClosed'
+      msg: 'Batch window closed'
+    },
+    {
+      code: 6050
+      name: 'batchNotEnded'
+      msg: 'Batch window not ended'
+    },
+    {
+      code: 6051
+      name: 'invalidMint'
+      msg: 'Invalid mint'
+    },
+    {
+      code: 6052
+      name: 'openInterestNotRecorded'
+      msg: 'Open interest not recorded'
+    },
+    {
+      code: 6053
+      name: 'marketHistoryRequired'
+      msg: 'Market history required'
+    },
+    {
+      code: 6054
+      name: 'seasonRequired'
+      msg: 'Season required'
+    },
+    {
+      code: 6055
+      name: 'seasonAlreadyJoined'
+      msg: 'Season already joined'
+    },
+    {
+      code: 6056
+      name: 'priceNotSettled'
+      msg: 'Trigger price not settled'
+    },
+    {
+      code: 6057
+      name: 'batchNotCleared'
+      msg: 'Batch not cleared'
+    },
+    {
+      code: 6058
+      name: 'arithmeticOverflow'
+      msg: 'Arithmetic overflow'
+    }
+  ]
+  types: [
+    {
+      name: 'addLiquidityArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'amount'
+            type: 'u64'
+          },
+          {
+            name: 'direction'
+            type: {
+              defined: {
+                name: 'orderDirection'
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'baseAssetV1'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'key'
+            type: {
+              defined: {
+                name: 'key'
+              }
+            }
+          },
+          {
+            name: 'owner'
+            type: 'pubkey'
+          },
+          {
+            name: 'updateAuthority'
+            type: {
+              defined: {
+                name: 'updateAuthority'
+              }
+            }
+          },
+          {
+            name: 'name'
+            type: 'string'
+          },
+          {
+            name: 'uri'
+            type: 'string'
+          },
+          {
+            name: 'seq'
+            type: {
+              option: 'u64'
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'baseCollectionV1'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'key'
+            type: {
+              defined: {
+                name: 'key'
+              }
+            }
+          },
+          {
+            name: 'updateAuthority'
+            type: 'pubkey'
+          },
+          {
+            name: 'name'
+            type: 'string'
+          },
+          {
+            name: 'uri'
+            type: 'string'
+          },
+          {
+            name: 'numMinted'
+            type: 'u32'
+          },
+          {
+            name: 'currentSize'
+            type: 'u32'
+          }
+        ]
+      }
+    },
+    {
+      name: 'batchAuction'
+      docs: [
+        'Companion of a `MarketMode::BatchAuction` market collecting the orders of the current',
+        'batch window until a crank clears them at a single price'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+          {
+            name: 'market'
+            type: 'pubkey'
+          },
+          {
+            name: 'batchDuration'
+            docs: ['Length of each batch window (in seconds)']
+            type: 'i64'
+          },
+          {
+            name: 'batchId'
+            docs: ['Batch currently collecting or being cleared']
+            type: 'u64'
+          },
+          {
+            name: 'batchEnd'
+            docs: [
+              'Orders are accepted until this timestamp, then the batch waits for the crank'
+            ]
+            type: 'i64'
+          },
+          {
+            name: 'hypeAmount'
+            docs: [
+              'Collateral submitted to each side during the batch, fees excluded (in TRD)'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'flopAmount'
+            type: 'u64'
+          },
+          {
+            name: 'pendingOrders'
+            docs: ['Orders of the batch not filled yet']
+            type: 'u64'
+          },
+          {
+            name: 'isClearing'
+            docs: [
+              'Set once the clearing prices are fixed, until every order of the batch is filled'
+            ]
+            type: 'bool'
+          },
+          {
+            name: 'clearingHypePrice'
+            docs: [
+              'Uniform prices of the batch being cleared (0-1000000, representing 0 to 1 TRD)'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'clearingFlopPrice'
+            type: 'u64'
+          },
+          {
+            name: 'padding'
+            type: {
+              array: ['u8', 32]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'batchAuctionUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'seq'
+            type: 'u64'
+          },
+          {
+            name: 'batchId'
+            type: 'u64'
+          },
+          {
+            name: 'batchEnd'
+            type: 'i64'
+          },
+          {
+            name: 'hypeAmount'
+            type: 'u64'
+          },
+          {
+            name: 'flopAmount'
+            type: 'u64'
+          },
+          {
+            name: 'pendingOrders'
+            type: 'u64'
+          },
+          {
+            name: 'isClearing'
+            type: 'bool'
+          },
+          {
+            name: 'clearingHypePrice'
+            type: 'u64'
+          },
+          {
+            name: 'clearingFlopPrice'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'bondStatus'
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'posted'
+          },
+          {
+            name: 'returned'
+          },
+          {
+            name: 'slashed'
+          }
+        ]
+      }
+    },
+    {
+      name: 'candle'
+      serialization: 'bytemuck'
+      repr: {
+        kind: 'c'
+      }
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'startTs'
+            type: 'i64'
+          },
+          {
+            name: 'open'
+            type: 'u64'
+          },
+          {
+            name: 'high'
+            type: 'u64'
+          },
+          {
+            name: 'low'
+            type: 'u64'
+          },
+          {
+            name: 'close'
+            type: 'u64'
+          },
+          {
+            name: 'volume'
+            docs: ['Traded volume (in TRD)']
+            type: 'u64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'claimStakeRewardsArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'rank'
+            type: 'u16'
+          },
+          {
+            name: 'collections'
+            type: 'u8'
+          }
+        ]
+      }
+    },
+    {
+      name: 'collection'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'authority'
+            type: 'pubkey'
+          },
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+          {
+            name: 'symbol'
+            type: 'string'
+          },
+          {
+            name: 'minted'
+            type: 'u64'
+          },
+          {
+            name: 'supply'
+            type: 'u64'
+          },
+          {
+            name: 'padding'
+            type: {
+              array: ['u8', 64]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'collectionUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'collection'
+            type: 'pubkey'
+          },
+          {
+            name: 'coreCollection'
+            type: 'pubkey'
+          },
+          {
+            name: 'symbol'
+            type: 'string'
+          },
+          {
+            name: 'supply'
+            type: 'u64'
+          },
+          {
+            name: 'minted'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'createBatchAuctionArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'batchDuration'
+            type: 'i64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'createCollectionArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'name'
+            type: 'string'
+          },
+          {
+            name: 'symbol'
+            type: 'string'
+          },
+          {
+            name: 'supply'
+            type: 'u64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'createMarketArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'question'
+            type: {
+              array: ['u8', 80]
+            }
+          },
+          {
+            name: 'startTime'
+            type: 'i64'
+          },
+          {
+            name: 'endTime'
+            type: 'i64'
+          },
+          {
+            name: 'resolver'
+            docs: ['Account allowed to resolve the market alongside the admin']
+            type: 'pubkey'
+          },
+          {
+            name: 'seedLiquidity'
+            docs: [
+              'Collateral seeded into the pools, split evenly between Hype and Flop'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'creatorFeeBps'
+            docs: [
+              'Share of the market fee paid to the creator (in basis points of the market fee)'
+            ]
+            type: 'u16'
+          }
+        ]
+      }
+    },
+    {
+      name: 'createMarketHistoryArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'interval'
+            type: 'i64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'createNftRewardPoolArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'epochDuration'
+            type: 'i64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'createSeasonArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'seasonId'
+            type: 'u64'
+          },
+          {
+            name: 'startTs'
+            type: 'i64'
+          },
+          {
+            name: 'endTs'
+            type: 'i64'
+          },
+          {
+            name: 'scoreType'
+            type: {
+              defined: {
+                name: 'seasonScoreType'
+              }
+            }
+          },
+          {
+            name: 'eligibleMarkets'
+            type: {
+              vec: 'u64'
+            }
+          },
+          {
+            name: 'prizeBps'
+            type: {
+              vec: 'u16'
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'createUserArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'name'
+            type: 'string'
+          }
+        ]
+      }
+    },
+    {
+      name: 'createVotingMarketArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'voteId'
+            type: 'u64'
+          },
+          {
+            name: 'question'
+            type: {
+              array: ['u8', 80]
+            }
+          },
+          {
+            name: 'resolutionSource'
+            type: {
+              array: ['u8', 80]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'feeCollected'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'seq'
+            type: 'u64'
+          },
+          {
+            name: 'authority'
+            type: 'pubkey'
+          },
+          {
+            name: 'marketFee'
+            type: 'u64'
+          },
+          {
+            name: 'nftHoldersFee'
+            type: 'u64'
+          },
+          {
+            name: 'creatorFee'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'fundSeasonArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'amount'
+            type: 'u64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'initializeMarketArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'name'
+            type: 'string'
+          },
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'question'
+            type: {
+              array: ['u8', 80]
+            }
+          },
+          {
+            name: 'startTime'
+            type: 'i64'
+          },
+          {
+            name: 'endTime'
+            type: 'i64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'key'
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'uninitialized'
+          },
+          {
+            name: 'assetV1'
+          },
+          {
+            name: 'hashedAssetV1'
+          },
+          {
+            name: 'pluginHeaderV1'
+          },
+          {
+            name: 'pluginRegistryV1'
+          },
+          {
+            name: 'collectionV1'
+          }
+        ]
+      }
+    },
+    {
+      name: 'liquidityUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'seq'
+            type: 'u64'
+          },
+          {
+            name: 'user'
+            type: 'pubkey'
+          },
+          {
+            name: 'direction'
+            type: {
+              defined: {
+                name: 'orderDirection'
+              }
+            }
+          },
+          {
+            name: 'amount'
+            type: 'u64'
+          },
+          {
+            name: 'hypeLiquidity'
+            type: 'u64'
+          },
+          {
+            name: 'flopLiquidity'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'market'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+          {
+            name: 'authority'
+            type: 'pubkey'
+          },
+          {
+            name: 'marketId'
+            docs: ['Unique identifier for the market']
+            type: 'u64'
+          },
+          {
+            name: 'name'
+            docs: ['The event being predicted (e.g., "tJUP/TRD")']
+            type: 'string'
+          },
+          {
+            name: 'hypePrice'
+            docs: [
+              'Current price for Hype outcome (0-1000000, representing 0 to 1 TRD)',
+              '1000000 = 1 TRD, 500000 = 0.5 TRD, etc.'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'flopPrice'
+            docs: [
+              'Current price for Flop outcome (0-1000000, representing 0 to 1 TRD)'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'hypeLiquidity'
+            docs: ['Total liquidity for Hype (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'flopLiquidity'
+            docs: ['Total liquidity for Flop (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'totalHypeShares'
+            docs: ['Total number of Hype shares issued']
+            type: 'u64'
+          },
+          {
+            name: 'totalFlopShares'
+            docs: ['Total number of Flop shares issued']
+            type: 'u64'
+          },
+          {
+            name: 'totalVolume'
+            docs: ['Total trading volume (in TRD) for all resolutions']
+            type: 'u64'
+          },
+          {
+            name: 'mint'
+            docs: ['Mint $TRD token']
+            type: 'pubkey'
+          },
+          {
+            name: 'ts'
+            docs: ['Timestamp of the init']
+            type: 'i64'
+          },
+          {
+            name: 'updateTs'
+            type: 'i64'
+          },
+          {
+            name: 'openOrdersCount'
+            docs: ['Total number of open orders in this market']
+            type: 'u64'
+          },
+          {
+            name: 'nextOrderId'
+            docs: ['Next available order ID']
+            type: 'u64'
+          },
+          {
+            name: 'feeBps'
+            docs: ['Fees applied to trades (in basis points, e.g., 2.131% fee)']
+            type: 'u16'
+          },
+          {
+            name: 'feeVault'
+            docs: ['Vault to Receive fees']
+            type: 'pubkey'
+          },
+          {
+            name: 'isActive'
+            docs: ['Whether the market is currently active for trading']
+            type: 'bool'
+          },
+          {
+            name: 'marketPrice'
+            type: 'u64'
+          },
+          {
+            name: 'previousResolvedQuestion'
+            type: {
+              defined: {
+                name: 'resolvedQuestion'
+              }
+            }
+          },
+          {
+            name: 'currentQuestionId'
+            docs: [
+              'Index of the current week in the weekly_results array initialized with default values'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'currentQuestionStart'
+            type: 'i64'
+          },
+          {
+            name: 'currentQuestionEnd'
+            type: 'i64'
+          },
+          {
+            name: 'currentQuestion'
+            docs: ['The question or prediction topic for the current week']
+            type: {
+              array: ['u8', 80]
+            }
+          },
+          {
+            name: 'liquidity'
+            type: 'u64'
+          },
+          {
+            name: 'eventSeq'
+            docs: ['Sequence number of the last event emitted for this market']
+            type: 'u64'
+          },
+          {
+            name: 'padding'
+            type: {
+              array: ['u8', 192]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'marketBond'
+      docs: ['Bond posted by the creator of a permissionless market']
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+          {
+            name: 'market'
+            type: 'pubkey'
+          },
+          {
+            name: 'creator'
+            type: 'pubkey'
+          },
+          {
+            name: 'resolver'
+            docs: ['Account allowed to resolve the market alongside the admin']
+            type: 'pubkey'
+          },
+          {
+            name: 'amount'
+            docs: ['Bond posted by the creator (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'seedLiquidity'
+            docs: [
+              'Collateral seeded into the market pools by the creator (in TRD)'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'status'
+            type: {
+              defined: {
+                name: 'bondStatus'
+              }
+            }
+          },
+          {
+            name: 'padding'
+            type: {
+              array: ['u8', 32]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'marketBondUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'seq'
+            type: 'u64'
+          },
+          {
+            name: 'creator'
+            type: 'pubkey'
+          },
+          {
+            name: 'resolver'
+            type: 'pubkey'
+          },
+          {
+            name: 'amount'
+            type: 'u64'
+          },
+          {
+            name: 'status'
+            type: {
+              defined: {
+                name: 'bondStatus'
+              }
+            }
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'marketHistory'
+      docs: [
+        'Ring buffer of Hype price candles for a market, prices follow `MarketV2::hype_price`'
+      ]
+      serialization: 'bytemuck'
+      repr: {
+        kind: 'c'
+      }
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'market'
+            type: 'pubkey'
+          },
+          {
+            name: 'interval'
+            docs: ['Length of each candle (in seconds)']
+            type: 'i64'
+          },
+          {
+            name: 'head'
+            docs: ['Index of the latest candle']
+            type: 'u64'
+          },
+          {
+            name: 'len'
+            docs: ['Number of candles written, up to `MAX_CANDLES`']
+            type: 'u64'
+          },
+          {
+            name: 'candles'
+            type: {
+              array: [
+                {
+                  defined: {
+                    name: 'candle'
+                  }
+                },
+                128
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'marketHistoryUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'seq'
+            type: 'u64'
+          },
+          {
+            name: 'marketHistory'
+            type: 'pubkey'
+          },
+          {
+            name: 'interval'
+            type: 'i64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'marketLimitsUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'seq'
+            type: 'u64'
+          },
+          {
+            name: 'maxUserStake'
+            type: 'u64'
+          },
+          {
+            name: 'maxOrderLiquidityBps'
+            type: 'u16'
+          },
+          {
+            name: 'maxOpenInterest'
+            type: 'u64'
+          },
+          {
+            name: 'referralFeeBps'
+            type: 'u16'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'marketMetadata'
+      docs: ['Display and resolution details of a market, sized to its content']
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+          {
+            name: 'market'
+            type: 'pubkey'
+          },
+          {
+            name: 'updateTs'
+            type: 'i64'
+          },
+          {
+            name: 'question'
+            type: 'string'
+          },
+          {
+            name: 'rules'
+            docs: ['Criteria used to resolve the market']
+            type: 'string'
+          },
+          {
+            name: 'sources'
+            type: {
+              vec: 'string'
+            }
+          },
+          {
+            name: 'category'
+            type: 'string'
+          },
+          {
+            name: 'tags'
+            type: {
+              vec: 'string'
+            }
+          },
+          {
+            name: 'image'
+            type: 'string'
+          }
+        ]
+      }
+    },
+    {
+      name: 'marketMetadataArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'question'
+            type: 'string'
+          },
+          {
+            name: 'rules'
+            type: 'string'
+          },
+          {
+            name: 'sources'
+            type: {
+              vec: 'string'
+            }
+          },
+          {
+            name: 'category'
+            type: 'string'
+          },
+          {
+            name: 'tags'
+            type: {
+              vec: 'string'
+            }
+          },
+          {
+            name: 'image'
+            type: 'string'
+          }
+        ]
+      }
+    },
+    {
+      name: 'marketMetadataUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'seq'
+            type: 'u64'
+          },
+          {
+            name: 'marketMetadata'
+            type: 'pubkey'
+          },
+          {
+            name: 'question'
+            type: 'string'
+          },
+          {
+            name: 'category'
+            type: 'string'
+          },
+          {
+            name: 'image'
+            type: 'string'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'marketMode'
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'amm'
+          },
+          {
+            name: 'batchAuction'
+          }
+        ]
+      }
+    },
+    {
+      name: 'marketUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'seq'
+            type: 'u64'
+          },
+          {
+            name: 'question'
+            type: 'string'
+          },
+          {
+            name: 'startTime'
+            type: 'i64'
+          },
+          {
+            name: 'endTime'
+            type: 'i64'
+          },
+          {
+            name: 'hypeLiquidity'
+            type: 'u64'
+          },
+          {
+            name: 'flopLiquidity'
+            type: 'u64'
+          },
+          {
+            name: 'winningDirection'
+            type: {
+              defined: {
+                name: 'winningDirection'
+              }
+            }
+          },
+          {
+            name: 'finalHypePrice'
+            type: 'u64'
+          },
+          {
+            name: 'finalFlopPrice'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          },
+          {
+            name: 'totalHypeShares'
+            type: 'u64'
+          },
+          {
+            name: 'totalFlopShares'
+            type: 'u64'
+          },
+          {
+            name: 'payoutPerShare'
+            type: 'u64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'marketV2'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+          {
+            name: 'authority'
+            type: 'pubkey'
+          },
+          {
+            name: 'marketId'
+            docs: ['Unique identifier for the market']
+            type: 'u64'
+          },
+          {
+            name: 'hypePrice'
+            type: 'u64'
+          },
+          {
+            name: 'flopPrice'
+            type: 'u64'
+          },
+          {
+            name: 'hypeLiquidity'
+            docs: ['Total liquidity for Hype (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'flopLiquidity'
+            docs: ['Total liquidity for Flop (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'hypeShares'
+            docs: ['Total number of Hype shares issued']
+            type: 'u64'
+          },
+          {
+            name: 'flopShares'
+            docs: ['Total number of Flop shares issued']
+            type: 'u64'
+          },
+          {
+            name: 'volume'
+            docs: ['Total trading volume (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'mint'
+            docs: ['Mint $TRD token']
+            type: 'pubkey'
+          },
+          {
+            name: 'updateTs'
+            type: 'i64'
+          },
+          {
+            name: 'openedOrders'
+            docs: ['Total number of open orders in this market']
+            type: 'u64'
+          },
+          {
+            name: 'nextOrderId'
+            docs: ['Next available order ID']
+            type: 'u64'
+          },
+          {
+            name: 'feeBps'
+            docs: ['Fees applied to trades (in basis points, e.g., 2.131% fee)']
+            type: 'u16'
+          },
+          {
+            name: 'nftHoldersFeeAvailable'
+            type: 'u64'
+          },
+          {
+            name: 'nftHoldersFeeClaimed'
+            type: 'u64'
+          },
+          {
+            name: 'marketMakerFeeAvailable'
+            type: 'u64'
+          },
+          {
+            name: 'marketMakerFeeClaimed'
+            type: 'u64'
+          },
+          {
+            name: 'marketMakerId'
+            type: 'u64'
+          },
+          {
+            name: 'marketFeeAvailable'
+            type: 'u64'
+          },
+          {
+            name: 'marketFeeClaimed'
+            type: 'u64'
+          },
+          {
+            name: 'isActive'
+            docs: ['Whether the market is currently active for trading']
+            type: 'bool'
+          },
+          {
+            name: 'marketStart'
+            docs: [
+              'Index of the current week in the weekly_results array initialized with default values'
+            ]
+            type: 'i64'
+          },
+          {
+            name: 'marketEnd'
+            type: 'i64'
+          },
+          {
+            name: 'question'
+            docs: ['The question or prediction topic for the current week']
+            type: {
+              array: ['u8', 80]
+            }
+          },
+          {
+            name: 'winningDirection'
+            type: {
+              defined: {
+                name: 'winningDirection'
+              }
+            }
+          },
+          {
+            name: 'marketLiquidityAtStart'
+            type: 'u64'
+          },
+          {
+            name: 'settledWinningShares'
+            docs: [
+              'Winning side shares outstanding at resolution, open interest for a voided market'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'settledPool'
+            docs: [
+              'Collateral owed to the winning side at resolution, seed liquidity excluded (in TRD)'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'settledSeedLiquidity'
+            docs: [
+              'Seed liquidity excluded from the settlement pool, 0 until the market is resolved'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'payoutPerShare'
+            docs: [
+              'Fixed payout per winning share, or per TRD of cost basis for a voided market',
+              '(0-1000000, representing 0 to 1 TRD)'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'settledClaimed'
+            docs: ['Total paid out to winning orders since resolution (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'creatorFeeBps'
+            docs: [
+              'Share of the market fee paid to the market creator (in basis points of the market fee)'
+            ]
+            type: 'u16'
+          },
+          {
+            name: 'openInterest'
+            docs: ['Collateral committed by the open orders (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'maxUserStake'
+            docs: [
+              'Most collateral a single user can hold in open orders, 0 for no limit (in TRD)'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'maxOrderLiquidityBps'
+            docs: [
+              'Largest order relative to the market liquidity, 0 for no limit (in basis points)'
+            ]
+            type: 'u16'
+          },
+          {
+            name: 'maxOpenInterest'
+            docs: [
+              'Most collateral the open orders can hold in total, 0 for no limit (in TRD)'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'hypePriceCumulative'
+            docs: [
+              'Running sum of `hype_price` weighted by the seconds it was quoted, wraps on overflow.',
+              'Advanced to `update_ts`.'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'eventSeq'
+            docs: ['Sequence number of the last event emitted for this market']
+            type: 'u64'
+          },
+          {
+            name: 'referralFeeBps'
+            docs: [
+              'Share of the market fee paid to the trader\'s referrer, 0 until set by the market',
+              'authority (in basis points of the market fee)'
+            ]
+            type: 'u16'
+          },
+          {
+            name: 'collateralDecimals'
+            docs: [
+              'Decimals of the collateral mint, 0 for markets created before they were recorded (TRD)'
+            ]
+            type: 'u8'
+          },
+          {
+            name: 'mode'
+            docs: [
+              'How orders are priced, batch auction markets carry a `BatchAuction` companion'
+            ]
+            type: {
+              defined: {
+                name: 'marketMode'
+              }
+            }
+          },
+          {
+            name: 'hasHistory'
+            docs: [
+              'Whether a `MarketHistory` exists, trades must then record their candle'
+            ]
+            type: 'bool'
+          },
+          {
+            name: 'twapCheckpointCumulative'
+            docs: [
+              'Accumulator checkpoint at least `TRIGGER_TWAP_WINDOW` old, order triggers fire on the',
+              'TWAP since then'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'twapCheckpointTs'
+            type: 'i64'
+          },
+          {
+            name: 'nextCheckpointCumulative'
+            docs: [
+              'Latest checkpoint, replaces the one above once a full window old'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'nextCheckpointTs'
+            type: 'i64'
+          },
+          {
+            name: 'padding'
+            type: {
+              array: ['u8', 3]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'mintCompleteSetArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'amount'
+            docs: [
+              'Collateral to deposit, 1 unit mints 1 Hype share and 1 Flop share'
+            ]
+            type: 'u64'
+          }
+        ]
+      }
+    },
     {
-      name: 'marketUpdate'
-      discriminator: [170, 101, 124, 32, 249, 253, 251, 96]
+      name: 'mintTicketArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'collectionSymbol'
+            type: 'string'
+          },
+          {
+            name: 'discount'
+            type: 'u64'
+          },
+          {
+            name: 'isBoosted'
+            type: 'bool'
+          },
+          {
+            name: 'number'
+            type: 'u64'
+          },
+          {
+            name: 'rarity'
+            type: {
+              defined: {
+                name: 'rarity'
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      name: 'orderUpdate'
-      discriminator: [97, 239, 148, 96, 83, 234, 245, 14]
+      name: 'nft'
+      type: {
+        kind: 'struct'
+        fields: []
+      }
     },
     {
-      name: 'priceUpdate'
-      discriminator: [222, 51, 180, 226, 165, 188, 203, 54]
+      name: 'nftRewardClaim'
+      docs: [
+        'Last epoch claimed by a ticket, keyed by the pool and the asset so it follows the ticket',
+        'when sold. Created at mint with the last closed epoch, so a ticket only shares the epochs',
+        'closed after it was minted.'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+          {
+            name: 'rewardPool'
+            type: 'pubkey'
+          },
+          {
+            name: 'asset'
+            type: 'pubkey'
+          },
+          {
+            name: 'epoch'
+            type: 'u64'
+          },
+          {
+            name: 'totalClaimed'
+            type: 'u64'
+          },
+          {
+            name: 'padding'
+            type: {
+              array: ['u8', 32]
+            }
+          }
+        ]
+      }
     },
     {
-      name: 'stakeRewards'
-      discriminator: [236, 217, 227, 239, 6, 129, 188, 218]
-    }
-  ]
-  errors: [
+      name: 'nftRewardEpoch'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'rewardPool'
+            type: 'pubkey'
+          },
+          {
+            name: 'epoch'
+            type: 'u64'
+          },
+          {
+            name: 'rewardPerAsset'
+            type: 'u64'
+          },
+          {
+            name: 'epochRewards'
+            type: 'u64'
+          },
+          {
+            name: 'pendingRewards'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
+    },
     {
-      code: 6000
-      name: 'unauthorized'
-      msg: 'Unauthorized access'
+      name: 'nftRewardPool'
+      docs: [
+        'Pool of the NFT holders\' share of trading fees, paid out per epoch to every ticket',
+        'of the collection'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+          {
+            name: 'collection'
+            type: 'pubkey'
+          },
+          {
+            name: 'coreCollection'
+            type: 'pubkey'
+          },
+          {
+            name: 'mint'
+            type: 'pubkey'
+          },
+          {
+            name: 'epoch'
+            docs: ['Last closed epoch, the only one tickets can claim']
+            type: 'u64'
+          },
+          {
+            name: 'epochStart'
+            type: 'i64'
+          },
+          {
+            name: 'epochDuration'
+            docs: ['Length of each epoch (in seconds)']
+            type: 'i64'
+          },
+          {
+            name: 'pendingRewards'
+            docs: ['Fees received since the last epoch was closed (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'rewardPerAsset'
+            docs: ['Reward each ticket can claim for `epoch` (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'epochRewards'
+            type: 'u64'
+          },
+          {
+            name: 'epochClaimed'
+            type: 'u64'
+          },
+          {
+            name: 'totalClaimed'
+            type: 'u64'
+          },
+          {
+            name: 'padding'
+            type: {
+              array: ['u8', 64]
+            }
+          }
+        ]
+      }
     },
     {
-      code: 6001
-      name: 'invalidPosition'
-      msg: 'Invalid position'
+      name: 'nftRewardsClaimed'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'user'
+            type: 'pubkey'
+          },
+          {
+            name: 'asset'
+            type: 'pubkey'
+          },
+          {
+            name: 'rewardPool'
+            type: 'pubkey'
+          },
+          {
+            name: 'epoch'
+            type: 'u64'
+          },
+          {
+            name: 'amount'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
     },
     {
-      code: 6002
-      name: 'invalidWithdrawAmount'
-      msg: 'Invalid withdraw amount'
+      name: 'openOrderArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'amount'
+            type: 'u64'
+          },
+          {
+            name: 'direction'
+            type: {
+              defined: {
+                name: 'orderDirection'
+              }
+            }
+          },
+          {
+            name: 'maxPrice'
+            docs: ['Highest price the order may fill at, required']
+            type: 'u64'
+          }
+        ]
+      }
     },
     {
-      code: 6003
-      name: 'stakeLocked'
-      msg: 'Stake is locked'
+      name: 'order'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'ts'
+            type: 'i64'
+          },
+          {
+            name: 'orderId'
+            type: 'u64'
+          },
+          {
+            name: 'questionId'
+            type: 'u64'
+          },
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'status'
+            type: {
+              defined: {
+                name: 'orderStatus'
+              }
+            }
+          },
+          {
+            name: 'price'
+            docs: ['The price of the order (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'totalAmount'
+            docs: ['The total amount of TRD committed to this order']
+            type: 'u64'
+          },
+          {
+            name: 'totalShares'
+            docs: ['The total number of shares to be purchased']
+            type: 'u64'
+          },
+          {
+            name: 'orderType'
+            type: {
+              defined: {
+                name: 'orderType'
+              }
+            }
+          },
+          {
+            name: 'direction'
+            type: {
+              defined: {
+                name: 'orderDirection'
+              }
+            }
+          },
+          {
+            name: 'stopLossPrice'
+            docs: [
+              'Price at or below which a keeper may close the order, 0 when unset'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'takeProfitPrice'
+            docs: [
+              'Price at or above which a keeper may close the order, 0 when unset'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'batchId'
+            docs: [
+              'Batch auction window the order was submitted in, 0 for AMM orders'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'padding'
+            type: {
+              array: ['u8', 8]
+            }
+          }
+        ]
+      }
     },
     {
-      code: 6004
-      name: 'insufficientFunds'
-      msg: 'Insufficient funds'
+      name: 'orderDirection'
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'hype'
+          },
+          {
+            name: 'flop'
+          }
+        ]
+      }
     },
     {
-      code: 6005
-      name: 'noRewardsAvailable'
-      msg: 'No rewards available'
+      name: 'orderStatus'
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'init'
+          },
+          {
+            name: 'open'
+          },
+          {
+            name: 'closed'
+          },
+          {
+            name: 'claimed'
+          },
+          {
+            name: 'liquidated'
+          },
+          {
+            name: 'pending'
+          }
+        ]
+      }
     },
     {
-      code: 6006
-      name: 'invalidPrice'
-      msg: 'Invalid price'
+      name: 'orderTriggered'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'user'
+            type: 'pubkey'
+          },
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'seq'
+            type: 'u64'
+          },
+          {
+            name: 'orderId'
+            type: 'u64'
+          },
+          {
+            name: 'keeper'
+            type: 'pubkey'
+          },
+          {
+            name: 'price'
+            type: 'u64'
+          },
+          {
+            name: 'keeperFee'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
     },
     {
-      code: 6007
-      name: 'noAvailableOrderSlot'
-      msg: 'No available order slot'
+      name: 'orderTriggersUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'user'
+            type: 'pubkey'
+          },
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'seq'
+            type: 'u64'
+          },
+          {
+            name: 'orderId'
+            type: 'u64'
+          },
+          {
+            name: 'stopLossPrice'
+            type: 'u64'
+          },
+          {
+            name: 'takeProfitPrice'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
     },
     {
-      code: 6008
-      name: 'marketInactive'
-      msg: 'Market is inactive'
+      name: 'orderType'
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'market'
+          },
+          {
+            name: 'limit'
+          }
+        ]
+      }
     },
     {
-      code: 6009
-      name: 'orderNotFound'
-      msg: 'Order not found'
+      name: 'orderUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'user'
+            type: 'pubkey'
+          },
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'seq'
+            type: 'u64'
+          },
+          {
+            name: 'questionId'
+            type: 'u64'
+          },
+          {
+            name: 'orderId'
+            type: 'u64'
+          },
+          {
+            name: 'direction'
+            type: {
+              defined: {
+                name: 'orderDirection'
+              }
+            }
+          },
+          {
+            name: 'orderType'
+            type: {
+              defined: {
+                name: 'orderType'
+              }
+            }
+          },
+          {
+            name: 'orderStatus'
+            type: {
+              defined: {
+                name: 'orderStatus'
+              }
+            }
+          },
+          {
+            name: 'price'
+            type: 'u64'
+          },
+          {
+            name: 'totalShares'
+            type: 'u64'
+          },
+          {
+            name: 'totalAmount'
+            type: 'u64'
+          },
+          {
+            name: 'refundAmount'
+            type: {
+              option: 'u64'
+            }
+          },
+          {
+            name: 'pnl'
+            type: 'i64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          },
+          {
+            name: 'isQuestionWinner'
+            type: {
+              option: 'bool'
+            }
+          }
+        ]
+      }
     },
     {
-      code: 6010
-      name: 'questionPeriodNotStarted'
-      msg: 'Question period not started'
+      name: 'position'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'amount'
+            type: 'u64'
+          },
+          {
+            name: 'entryPrice'
+            type: 'u64'
+          },
+          {
+            name: 'ts'
+            type: 'i64'
+          },
+          {
+            name: 'isLong'
+            type: 'bool'
+          },
+          {
+            name: 'isOpen'
+            type: 'bool'
+          },
+          {
+            name: 'pnl'
+            type: 'i64'
+          }
+        ]
+      }
     },
     {
-      code: 6011
-      name: 'questionPeriodEnded'
-      msg: 'Question period ended'
+      name: 'priceObservation'
+      docs: [
+        'Snapshot of the price accumulator, two observations of the same market give the TWAP between them'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'hypePriceCumulative'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          },
+          {
+            name: 'hypePrice'
+            type: 'u64'
+          },
+          {
+            name: 'flopPrice'
+            type: 'u64'
+          }
+        ]
+      }
     },
     {
-      code: 6012
-      name: 'stakeVaultLocked'
-      msg: 'Stake vault is locked'
+      name: 'priceUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'seq'
+            type: 'u64'
+          },
+          {
+            name: 'hypePrice'
+            type: 'u64'
+          },
+          {
+            name: 'flopPrice'
+            type: 'u64'
+          },
+          {
+            name: 'direction'
+            type: {
+              defined: {
+                name: 'orderDirection'
+              }
+            }
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
     },
     {
-      code: 6013
-      name: 'marketStillActive'
-      msg: 'Market still active'
+      name: 'promoteVotingMarketArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
+            type: 'u64'
+          }
+        ]
+      }
     },
     {
-      code: 6014
-      name: 'orderNotOpen'
-      msg: 'Order not open'
+      name: 'rarity'
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'common'
+          },
+          {
+            name: 'uncommon'
+          },
+          {
+            name: 'rare'
+          },
+          {
+            name: 'epic'
+          },
+          {
+            name: 'legendary'
+          },
+          {
+            name: 'mythic'
+          }
+        ]
+      }
     },
     {
-      code: 6015
-      name: 'hasOpenedOrders'
-      msg: 'Has opened orders'
+      name: 'redeemCompleteSetArgs'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'hypeOrderId'
+            type: 'u64'
+          },
+          {
+            name: 'flopOrderId'
+            type: 'u64'
+          },
+          {
+            name: 'shares'
+            docs: [
+              'Shares to burn from each order, 1 Hype share + 1 Flop share redeems 1 unit'
+            ]
+            type: 'u64'
+          }
+        ]
+      }
     },
     {
-      code: 6016
-      name: 'insufficientLiquidity'
-      msg: 'Insufficient liquidity'
+      name: 'referralFeeUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
+            type: 'u64'
+          },
+          {
+            name: 'seq'
+            type: 'u64'
+          },
+          {
+            name: 'referrer'
+            type: 'pubkey'
+          },
+          {
+            name: 'trader'
+            type: 'pubkey'
+          },
+          {
+            name: 'amount'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
     },
     {
-      code: 6017
-      name: 'marketNotResolved'
-      msg: 'Market not resolved'
+      name: 'referralRewards'
+      docs: [
+        'Fees earned by a referrer `User` from the trades of the users it referred, one per mint'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+          {
+            name: 'user'
+            docs: [
+              'Referrer `User` account, matches the `referral` of the users it referred'
+            ]
+            type: 'pubkey'
+          },
+          {
+            name: 'mint'
+            type: 'pubkey'
+          },
+          {
+            name: 'earned'
+            docs: ['Total credited since creation (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'claimed'
+            type: 'u64'
+          },
+          {
+            name: 'padding'
+            type: {
+              array: ['u8', 32]
+            }
+          }
+        ]
+      }
     },
     {
-      code: 6018
-      name: 'marketAlreadyResolved'
-      msg: 'Market already resolved'
+      name: 'referralRewardsClaimed'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'referrer'
+            type: 'pubkey'
+          },
+          {
+            name: 'authority'
+            type: 'pubkey'
+          },
+          {
+            name: 'amount'
+            type: 'u64'
+          },
+          {
+            name: 'totalClaimed'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
     },
     {
-      code: 6019
-      name: 'concurrentTransaction'
-      msg: 'Concurrent transaction'
+      name: 'referralRewardsUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'referralRewards'
+            type: 'pubkey'
+          },
+          {
+            name: 'user'
+            type: 'pubkey'
+          },
+          {
+            name: 'mint'
+            type: 'pubkey'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
     },
     {
-      code: 6020
-      name: 'collectionFull'
-      msg: 'Collection is full'
-    }
-  ]
-  types: [
-    {
-      name: 'addLiquidityArgs'
+      name: 'resolvedQuestion'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'amount'
+            name: 'questionId'
             type: 'u64'
           },
           {
-            name: 'direction'
+            name: 'question'
+            docs: ['The question or prediction topic for this week']
+            type: {
+              array: ['u8', 80]
+            }
+          },
+          {
+            name: 'startTime'
+            docs: ['Start timestamp of the week']
+            type: 'i64'
+          },
+          {
+            name: 'endTime'
+            docs: ['End timestamp of the week']
+            type: 'i64'
+          },
+          {
+            name: 'hypeLiquidity'
+            docs: ['Total liquidity for Hype (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'flopLiquidity'
+            docs: ['Total liquidity for Flop (in TRD)']
+            type: 'u64'
+          },
+          {
+            name: 'winningDirection'
+            docs: ['The winning direction (Hype, Flop or None)']
             type: {
               defined: {
-                name: 'orderDirection'
+                name: 'winningDirection'
               }
             }
+          },
+          {
+            name: 'marketPrice'
+            type: 'u64'
+          },
+          {
+            name: 'finalHypePrice'
+            docs: ['Final price for Hype outcome at the end of the week']
+            type: 'u64'
+          },
+          {
+            name: 'finalFlopPrice'
+            docs: ['Final price for Flop outcome at the end of the week']
+            type: 'u64'
+          },
+          {
+            name: 'totalHypeShares'
+            docs: ['Total number of Hype shares issued']
+            type: 'u64'
+          },
+          {
+            name: 'totalFlopShares'
+            docs: ['Total number of Flop shares issued']
+            type: 'u64'
+          },
+          {
+            name: 'padding'
+            type: {
+              array: ['u8', 40]
+            }
           }
         ]
       }
     },
     {
-      name: 'baseCollectionV1'
+      name: 'season'
+      docs: [
+        'Trading competition over a time window, the top of `leaderboard` shares the prize pool'
+      ]
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'key'
+            name: 'bump'
+            type: 'u8'
+          },
+          {
+            name: 'authority'
+            type: 'pubkey'
+          },
+          {
+            name: 'seasonId'
+            type: 'u64'
+          },
+          {
+            name: 'mint'
+            type: 'pubkey'
+          },
+          {
+            name: 'startTs'
+            type: 'i64'
+          },
+          {
+            name: 'endTs'
+            type: 'i64'
+          },
+          {
+            name: 'scoreType'
             type: {
               defined: {
-                name: 'key'
+                name: 'seasonScoreType'
               }
             }
           },
           {
-            name: 'updateAuthority'
-            type: 'pubkey'
+            name: 'eligibleMarkets'
+            docs: ['Markets counted by the season, every market when empty']
+            type: {
+              array: ['u64', 16]
+            }
           },
           {
-            name: 'name'
-            type: 'string'
+            name: 'eligibleMarketsLen'
+            type: 'u8'
           },
           {
-            name: 'uri'
-            type: 'string'
+            name: 'prizePool'
+            docs: ['Funded prizes (in TRD)']
+            type: 'u64'
           },
           {
-            name: 'numMinted'
-            type: 'u32'
+            name: 'prizeBps'
+            docs: ['Share of the prize pool for each rank (in basis points)']
+            type: {
+              array: ['u16', 10]
+            }
           },
           {
-            name: 'currentSize'
-            type: 'u32'
+            name: 'claimedRanks'
+            docs: ['Bit `i` is set once rank `i` claimed its prize']
+            type: 'u16'
+          },
+          {
+            name: 'leaderboard'
+            docs: ['Best scores, highest first']
+            type: {
+              array: [
+                {
+                  defined: {
+                    name: 'seasonEntry'
+                  }
+                },
+                10
+              ]
+            }
+          },
+          {
+            name: 'settledPrizePool'
+            docs: [
+              'Prize pool frozen by the first claim, every rank is paid from it'
+            ]
+            type: 'u64'
+          },
+          {
+            name: 'padding'
+            type: {
+              array: ['u8', 24]
+            }
           }
         ]
       }
     },
     {
-      name: 'claimStakeRewardsArgs'
+      name: 'seasonEntry'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'rank'
-            type: 'u16'
+            name: 'user'
+            type: 'pubkey'
           },
           {
-            name: 'collections'
-            type: 'u8'
+            name: 'score'
+            type: 'i64'
           }
         ]
       }
     },
     {
-      name: 'collection'
+      name: 'seasonJoined'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'authority'
-            type: 'pubkey'
+            name: 'seasonId'
+            type: 'u64'
           },
           {
-            name: 'bump'
-            type: 'u8'
+            name: 'user'
+            type: 'pubkey'
           },
           {
-            name: 'symbol'
-            type: 'string'
+            name: 'endTs'
+            type: 'i64'
           },
           {
-            name: 'minted'
+            name: 'timestamp'
+            type: 'i64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'seasonPrizeClaimed'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'seasonId'
             type: 'u64'
           },
           {
-            name: 'supply'
+            name: 'user'
+            type: 'pubkey'
+          },
+          {
+            name: 'rank'
+            type: 'u8'
+          },
+          {
+            name: 'score'
+            type: 'i64'
+          },
+          {
+            name: 'amount'
             type: 'u64'
           },
           {
-            name: 'padding'
-            type: {
-              array: ['u8', 64]
-            }
+            name: 'timestamp'
+            type: 'i64'
           }
         ]
       }
     },
     {
-      name: 'createCollectionArgs'
+      name: 'seasonScore'
+      docs: ['A user\'s score in a season, created by `join_season`']
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'name'
-            type: 'string'
+            name: 'bump'
+            type: 'u8'
           },
           {
-            name: 'symbol'
-            type: 'string'
+            name: 'seasonId'
+            type: 'u64'
           },
           {
-            name: 'supply'
+            name: 'authority'
+            type: 'pubkey'
+          },
+          {
+            name: 'score'
+            type: 'i64'
+          },
+          {
+            name: 'volume'
             type: 'u64'
+          },
+          {
+            name: 'pnl'
+            type: 'i64'
           }
         ]
       }
     },
     {
-      name: 'createUserArgs'
+      name: 'seasonScoreType'
       type: {
-        kind: 'struct'
-        fields: [
+        kind: 'enum'
+        variants: [
           {
-            name: 'name'
-            type: 'string'
+            name: 'pnl'
+          },
+          {
+            name: 'volume'
           }
         ]
       }
     },
     {
-      name: 'initializeMarketArgs'
+      name: 'seasonUpdate'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'name'
-            type: 'string'
-          },
-          {
-            name: 'marketId'
+            name: 'seasonId'
             type: 'u64'
           },
           {
-            name: 'question'
-            type: {
-              array: ['u8', 80]
-            }
+            name: 'startTs'
+            type: 'i64'
           },
           {
-            name: 'startTime'
+            name: 'endTs'
             type: 'i64'
           },
           {
-            name: 'endTime'
+            name: 'prizePool'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
             type: 'i64'
           }
         ]
       }
     },
     {
-      name: 'key'
+      name: 'setOrderTriggersArgs'
       type: {
-        kind: 'enum'
-        variants: [
-          {
-            name: 'uninitialized'
-          },
-          {
-            name: 'assetV1'
-          },
-          {
-            name: 'hashedAssetV1'
-          },
+        kind: 'struct'
+        fields: [
           {
-            name: 'pluginHeaderV1'
+            name: 'orderId'
+            type: 'u64'
           },
           {
-            name: 'pluginRegistryV1'
+            name: 'stopLossPrice'
+            docs: ['0 clears the stop-loss']
+            type: 'u64'
           },
           {
-            name: 'collectionV1'
+            name: 'takeProfitPrice'
+            docs: ['0 clears the take-profit']
+            type: 'u64'
           }
         ]
       }
     },
     {
-      name: 'market'
+      name: 'solvencyReport'
       type: {
         kind: 'struct'
         fields: [
-          {
-            name: 'bump'
-            type: 'u8'
-          },
-          {
-            name: 'authority'
-            type: 'pubkey'
-          },
           {
             name: 'marketId'
-            docs: ['Unique identifier for the market']
             type: 'u64'
           },
           {
-            name: 'name'
-            docs: ['The event being predicted (e.g., "tJUP/TRD")']
-            type: 'string'
-          },
-          {
-            name: 'hypePrice'
-            docs: [
-              'Current price for Hype outcome (0-1000000, representing 0 to 1 TRD)',
-              '1000000 = 1 TRD, 500000 = 0.5 TRD, etc.'
-            ]
+            name: 'vaultBalance'
+            docs: ['Balance of the market vault (in TRD)']
             type: 'u64'
           },
           {
-            name: 'flopPrice'
-            docs: [
-              'Current price for Flop outcome (0-1000000, representing 0 to 1 TRD)'
-            ]
+            name: 'outstandingLiabilities'
+            docs: ['Most the market can still owe to traders (in TRD)']
             type: 'u64'
           },
           {
-            name: 'hypeLiquidity'
-            docs: ['Total liquidity for Hype (in TRD)']
+            name: 'unclaimedFees'
+            docs: ['Fees accrued and not yet collected (in TRD)']
             type: 'u64'
           },
           {
-            name: 'flopLiquidity'
-            docs: ['Total liquidity for Flop (in TRD)']
+            name: 'isSolvent'
+            type: 'bool'
+          }
+        ]
+      }
+    },
+    {
+      name: 'solvencyUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'marketId'
             type: 'u64'
           },
           {
-            name: 'totalHypeShares'
-            docs: ['Total number of Hype shares issued']
+            name: 'vaultBalance'
             type: 'u64'
           },
           {
-            name: 'totalFlopShares'
-            docs: ['Total number of Flop shares issued']
+            name: 'outstandingLiabilities'
             type: 'u64'
           },
           {
-            name: 'totalVolume'
-            docs: ['Total trading volume (in TRD) for all resolutions']
+            name: 'unclaimedFees'
             type: 'u64'
           },
           {
-            name: 'mint'
-            docs: ['Mint $TRD token']
-            type: 'pubkey'
+            name: 'isSolvent'
+            type: 'bool'
           },
           {
-            name: 'ts'
-            docs: ['Timestamp of the init']
+            name: 'timestamp'
             type: 'i64'
-          },
+          }
+        ]
+      }
+    },
+    {
+      name: 'stakeAction'
+      type: {
+        kind: 'enum'
+        variants: [
           {
-            name: 'updateTs'
-            type: 'i64'
+            name: 'stake'
           },
           {
-            name: 'openOrdersCount'
-            docs: ['Total number of open orders in this market']
-            type: 'u64'
+            name: 'requestWithdraw'
           },
           {
-            name: 'nextOrderId'
-            docs: ['Next available order ID']
-            type: 'u64'
+            name: 'withdraw'
           },
           {
-            name: 'feeBps'
-            docs: ['Fees applied to trades (in basis points, e.g., 2.131% fee)']
-            type: 'u16'
-          },
+            name: 'boost'
+          }
+        ]
+      }
+    },
+    {
+      name: 'stakeRewards'
+      type: {
+        kind: 'struct'
+        fields: [
           {
-            name: 'feeVault'
-            docs: ['Vault to Receive fees']
+            name: 'user'
             type: 'pubkey'
           },
           {
-            name: 'isActive'
-            docs: ['Whether the market is currently active for trading']
-            type: 'bool'
-          },
-          {
-            name: 'marketPrice'
-            type: 'u64'
-          },
-          {
-            name: 'previousResolvedQuestion'
-            type: {
-              defined: {
-                name: 'resolvedQuestion'
-              }
-            }
+            name: 'mint'
+            type: 'pubkey'
           },
           {
-            name: 'currentQuestionId'
-            docs: [
-              'Index of the current week in the weekly_results array initialized with default values'
-            ]
+            name: 'amount'
             type: 'u64'
           },
           {
-            name: 'currentQuestionStart'
-            type: 'i64'
-          },
-          {
-            name: 'currentQuestionEnd'
+            name: 'timestamp'
             type: 'i64'
           },
           {
-            name: 'currentQuestion'
-            docs: ['The question or prediction topic for the current week']
-            type: {
-              array: ['u8', 80]
-            }
-          },
-          {
-            name: 'liquidity'
-            type: 'u64'
-          },
-          {
-            name: 'padding'
-            type: {
-              array: ['u8', 200]
-            }
+            name: 'rank'
+            type: 'u16'
           }
         ]
       }
     },
     {
-      name: 'marketUpdate'
+      name: 'stakeTokenArgs'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'marketId'
-            type: 'u64'
+            name: 'stakeVault'
+            type: 'string'
           },
           {
-            name: 'question'
+            name: 'name'
             type: 'string'
           },
           {
-            name: 'startTime'
-            type: 'i64'
+            name: 'amount'
+            type: 'u64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'stakeUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'user'
+            type: 'pubkey'
           },
           {
-            name: 'endTime'
-            type: 'i64'
+            name: 'stake'
+            type: 'pubkey'
           },
           {
-            name: 'hypeLiquidity'
-            type: 'u64'
+            name: 'stakeVault'
+            type: 'pubkey'
           },
           {
-            name: 'flopLiquidity'
-            type: 'u64'
+            name: 'mint'
+            type: 'pubkey'
           },
           {
-            name: 'winningDirection'
+            name: 'action'
             type: {
               defined: {
-                name: 'winningDirection'
+                name: 'stakeAction'
               }
             }
           },
           {
-            name: 'finalHypePrice'
-            type: 'u64'
-          },
-          {
-            name: 'finalFlopPrice'
+            name: 'amount'
             type: 'u64'
           },
           {
-            name: 'timestamp'
+            name: 'withdrawTs'
             type: 'i64'
           },
           {
-            name: 'totalHypeShares'
-            type: 'u64'
+            name: 'boost'
+            type: 'bool'
           },
           {
-            name: 'totalFlopShares'
-            type: 'u64'
+            name: 'timestamp'
+            type: 'i64'
           }
         ]
       }
     },
     {
-      name: 'marketV2'
+      name: 'stakeV2'
       type: {
         kind: 'struct'
         fields: [
@@ -2769,168 +11557,160 @@ export type TriadProtocol = {
             type: 'pubkey'
           },
           {
-            name: 'marketId'
-            docs: ['Unique identifier for the market']
-            type: 'u64'
+            name: 'initTs'
+            type: 'i64'
           },
           {
-            name: 'hypePrice'
-            type: 'u64'
+            name: 'withdrawTs'
+            type: 'i64'
           },
           {
-            name: 'flopPrice'
-            type: 'u64'
+            name: 'claimedTs'
+            type: 'i64'
           },
           {
-            name: 'hypeLiquidity'
-            docs: ['Total liquidity for Hype (in TRD)']
-            type: 'u64'
+            name: 'name'
+            type: 'string'
           },
           {
-            name: 'flopLiquidity'
-            docs: ['Total liquidity for Flop (in TRD)']
-            type: 'u64'
+            name: 'mint'
+            type: 'pubkey'
           },
           {
-            name: 'hypeShares'
-            docs: ['Total number of Hype shares issued']
-            type: 'u64'
+            name: 'boost'
+            type: 'bool'
           },
           {
-            name: 'flopShares'
-            docs: ['Total number of Flop shares issued']
-            type: 'u64'
+            name: 'stakeVault'
+            type: 'pubkey'
           },
           {
-            name: 'volume'
-            docs: ['Total trading volume (in TRD)']
+            name: 'claimed'
             type: 'u64'
           },
           {
-            name: 'mint'
-            docs: ['Mint $TRD token']
-            type: 'pubkey'
+            name: 'available'
+            type: 'u64'
           },
           {
-            name: 'updateTs'
-            type: 'i64'
+            name: 'amount'
+            type: 'u64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'stakeVault'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'bump'
+            type: 'u8'
           },
           {
-            name: 'openedOrders'
-            docs: ['Total number of open orders in this market']
-            type: 'u64'
+            name: 'authority'
+            type: 'pubkey'
           },
           {
-            name: 'nextOrderId'
-            docs: ['Next available order ID']
-            type: 'u64'
+            name: 'initTs'
+            type: 'i64'
           },
           {
-            name: 'feeBps'
-            docs: ['Fees applied to trades (in basis points, e.g., 2.131% fee)']
-            type: 'u16'
+            name: 'endTs'
+            type: 'i64'
           },
           {
-            name: 'nftHoldersFeeAvailable'
+            name: 'amount'
             type: 'u64'
           },
           {
-            name: 'nftHoldersFeeClaimed'
+            name: 'amountPaid'
             type: 'u64'
           },
           {
-            name: 'marketFeeAvailable'
+            name: 'tokenDecimals'
+            type: 'u8'
+          },
+          {
+            name: 'nftStaked'
             type: 'u64'
           },
           {
-            name: 'marketFeeClaimed'
+            name: 'slots'
             type: 'u64'
           },
           {
-            name: 'isActive'
-            docs: ['Whether the market is currently active for trading']
+            name: 'isLocked'
             type: 'bool'
           },
           {
-            name: 'marketStart'
-            docs: [
-              'Index of the current week in the weekly_results array initialized with default values'
-            ]
-            type: 'i64'
+            name: 'name'
+            type: 'string'
           },
           {
-            name: 'marketEnd'
-            type: 'i64'
+            name: 'collection'
+            type: 'string'
           },
           {
-            name: 'question'
-            docs: ['The question or prediction topic for the current week']
-            type: {
-              array: ['u8', 80]
-            }
+            name: 'tokenMint'
+            type: 'pubkey'
           },
           {
-            name: 'winningDirection'
-            type: {
-              defined: {
-                name: 'winningDirection'
-              }
-            }
+            name: 'week'
+            type: 'u8'
           },
           {
-            name: 'marketLiquidityAtStart'
+            name: 'tokenStaked'
             type: 'u64'
           },
+          {
+            name: 'sumAllUsers'
+            type: 'f64'
+          },
           {
             name: 'padding'
             type: {
-              array: ['u8', 92]
+              array: ['u8', 32]
             }
           }
         ]
       }
     },
     {
-      name: 'mintTicketArgs'
+      name: 'stakeVaultUpdate'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'collectionSymbol'
-            type: 'string'
+            name: 'stakeVault'
+            type: 'pubkey'
           },
           {
-            name: 'discount'
+            name: 'amount'
             type: 'u64'
           },
           {
-            name: 'isBoosted'
-            type: 'bool'
+            name: 'tokenStaked'
+            type: 'u64'
           },
           {
-            name: 'number'
+            name: 'nftStaked'
             type: 'u64'
           },
           {
-            name: 'rarity'
-            type: {
-              defined: {
-                name: 'rarity'
-              }
-            }
+            name: 'isLocked'
+            type: 'bool'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
           }
         ]
       }
     },
     {
-      name: 'nft'
-      type: {
-        kind: 'struct'
-        fields: []
-      }
-    },
-    {
-      name: 'openOrderArgs'
+      name: 'submitBatchOrderArgs'
       type: {
         kind: 'struct'
         fields: [
@@ -2950,479 +11730,419 @@ export type TriadProtocol = {
       }
     },
     {
-      name: 'order'
+      name: 'ticketMinted'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'ts'
-            type: 'i64'
+            name: 'user'
+            type: 'pubkey'
           },
           {
-            name: 'orderId'
-            type: 'u64'
+            name: 'collection'
+            type: 'pubkey'
           },
           {
-            name: 'questionId'
-            type: 'u64'
+            name: 'asset'
+            type: 'pubkey'
           },
           {
-            name: 'marketId'
+            name: 'number'
             type: 'u64'
           },
           {
-            name: 'status'
+            name: 'rarity'
             type: {
               defined: {
-                name: 'orderStatus'
+                name: 'rarity'
               }
             }
           },
           {
-            name: 'price'
-            docs: ['The price of the order (in TRD)']
-            type: 'u64'
-          },
-          {
-            name: 'totalAmount'
-            docs: ['The total amount of TRD committed to this order']
-            type: 'u64'
+            name: 'isBoosted'
+            type: 'bool'
           },
           {
-            name: 'totalShares'
-            docs: ['The total number of shares to be purchased']
+            name: 'price'
             type: 'u64'
           },
           {
-            name: 'orderType'
-            type: {
-              defined: {
-                name: 'orderType'
-              }
-            }
-          },
-          {
-            name: 'direction'
-            type: {
-              defined: {
-                name: 'orderDirection'
-              }
-            }
-          },
-          {
-            name: 'padding'
-            type: {
-              array: ['u8', 32]
-            }
-          }
-        ]
-      }
-    },
-    {
-      name: 'orderDirection'
-      type: {
-        kind: 'enum'
-        variants: [
-          {
-            name: 'hype'
-          },
-          {
-            name: 'flop'
+            name: 'timestamp'
+            type: 'i64'
           }
         ]
       }
     },
     {
-      name: 'orderStatus'
+      name: 'updateAuthority'
       type: {
         kind: 'enum'
         variants: [
           {
-            name: 'init'
-          },
-          {
-            name: 'open'
-          },
-          {
-            name: 'closed'
-          },
-          {
-            name: 'claimed'
+            name: 'none'
           },
           {
-            name: 'liquidated'
-          }
-        ]
-      }
-    },
-    {
-      name: 'orderType'
-      type: {
-        kind: 'enum'
-        variants: [
-          {
-            name: 'market'
+            name: 'address'
+            fields: ['pubkey']
           },
           {
-            name: 'limit'
+            name: 'collection'
+            fields: ['pubkey']
           }
         ]
       }
     },
     {
-      name: 'orderUpdate'
+      name: 'updateMarketLimitsArgs'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'user'
-            type: 'pubkey'
-          },
-          {
-            name: 'marketId'
-            type: 'u64'
-          },
-          {
-            name: 'questionId'
-            type: 'u64'
-          },
-          {
-            name: 'orderId'
-            type: 'u64'
-          },
-          {
-            name: 'direction'
+            name: 'maxUserStake'
             type: {
-              defined: {
-                name: 'orderDirection'
-              }
+              option: 'u64'
             }
           },
           {
-            name: 'orderType'
+            name: 'maxOrderLiquidityBps'
             type: {
-              defined: {
-                name: 'orderType'
-              }
+              option: 'u16'
             }
           },
           {
-            name: 'orderStatus'
+            name: 'maxOpenInterest'
             type: {
-              defined: {
-                name: 'orderStatus'
-              }
+              option: 'u64'
             }
           },
           {
-            name: 'price'
-            type: 'u64'
-          },
-          {
-            name: 'totalShares'
-            type: 'u64'
-          },
-          {
-            name: 'totalAmount'
-            type: 'u64'
-          },
+            name: 'referralFeeBps'
+            type: {
+              option: 'u16'
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'updateStakeVaultArgs'
+      type: {
+        kind: 'struct'
+        fields: [
           {
-            name: 'refundAmount'
+            name: 'amount'
             type: {
               option: 'u64'
             }
           },
           {
-            name: 'pnl'
-            type: 'i64'
-          },
-          {
-            name: 'timestamp'
-            type: 'i64'
-          },
-          {
-            name: 'isQuestionWinner'
+            name: 'isLocked'
             type: {
               option: 'bool'
             }
+          },
+          {
+            name: 'stakeVault'
+            type: 'string'
           }
         ]
       }
     },
     {
-      name: 'position'
+      name: 'user'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'amount'
-            type: 'u64'
+            name: 'ts'
+            type: 'i64'
+          },
+          {
+            name: 'authority'
+            type: 'pubkey'
           },
           {
-            name: 'entryPrice'
-            type: 'u64'
+            name: 'bump'
+            type: 'u8'
           },
           {
-            name: 'ts'
+            name: 'referral'
+            type: 'pubkey'
+          },
+          {
+            name: 'referred'
             type: 'i64'
           },
           {
-            name: 'isLong'
-            type: 'bool'
+            name: 'name'
+            type: 'string'
           },
           {
-            name: 'isOpen'
-            type: 'bool'
+            name: 'swaps'
+            type: 'i16'
           },
           {
-            name: 'pnl'
+            name: 'swapsMade'
+            type: 'i16'
+          },
+          {
+            name: 'staked'
+            type: 'u64'
+          },
+          {
+            name: 'firstSwap'
             type: 'i64'
+          },
+          {
+            name: 'userTrade'
+            type: 'pubkey'
           }
         ]
       }
     },
     {
-      name: 'priceUpdate'
+      name: 'userPosition'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'marketId'
-            type: 'u64'
+            name: 'ts'
+            type: 'i64'
           },
           {
-            name: 'hypePrice'
-            type: 'u64'
+            name: 'bump'
+            type: 'u8'
           },
           {
-            name: 'flopPrice'
+            name: 'totalDeposited'
             type: 'u64'
           },
           {
-            name: 'direction'
-            type: {
-              defined: {
-                name: 'orderDirection'
-              }
-            }
-          },
-          {
-            name: 'timestamp'
-            type: 'i64'
-          }
-        ]
-      }
-    },
-    {
-      name: 'rarity'
-      type: {
-        kind: 'enum'
-        variants: [
-          {
-            name: 'common'
+            name: 'totalWithdrawn'
+            type: 'u64'
           },
           {
-            name: 'uncommon'
+            name: 'lpShare'
+            type: 'u64'
           },
           {
-            name: 'rare'
+            name: 'totalPositions'
+            type: 'u16'
           },
           {
-            name: 'epic'
+            name: 'ticker'
+            type: 'pubkey'
           },
           {
-            name: 'legendary'
+            name: 'authority'
+            type: 'pubkey'
           },
           {
-            name: 'mythic'
+            name: 'positions'
+            type: {
+              array: [
+                {
+                  defined: {
+                    name: 'position'
+                  }
+                },
+                3
+              ]
+            }
           }
         ]
       }
     },
     {
-      name: 'resolvedQuestion'
+      name: 'userStats'
+      docs: [
+        'Running trading stats of a user across the markets of one collateral mint, for leaderboards.',
+        'Amounts are given in that mint.'
+      ]
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'questionId'
-            type: 'u64'
-          },
-          {
-            name: 'question'
-            docs: ['The question or prediction topic for this week']
-            type: {
-              array: ['u8', 80]
-            }
+            name: 'bump'
+            type: 'u8'
           },
           {
-            name: 'startTime'
-            docs: ['Start timestamp of the week']
-            type: 'i64'
+            name: 'authority'
+            type: 'pubkey'
           },
           {
-            name: 'endTime'
-            docs: ['End timestamp of the week']
-            type: 'i64'
+            name: 'mint'
+            type: 'pubkey'
           },
           {
-            name: 'hypeLiquidity'
-            docs: ['Total liquidity for Hype (in TRD)']
+            name: 'volume'
+            docs: ['Collateral traded, opens and closes']
             type: 'u64'
           },
           {
-            name: 'flopLiquidity'
-            docs: ['Total liquidity for Flop (in TRD)']
+            name: 'feesPaid'
             type: 'u64'
           },
           {
-            name: 'winningDirection'
-            docs: ['The winning direction (Hype, Flop or None)']
-            type: {
-              defined: {
-                name: 'winningDirection'
-              }
-            }
+            name: 'realizedPnl'
+            docs: ['Sum of the pnl of closed and paid out orders']
+            type: 'i64'
           },
           {
-            name: 'marketPrice'
+            name: 'wins'
             type: 'u64'
           },
           {
-            name: 'finalHypePrice'
-            docs: ['Final price for Hype outcome at the end of the week']
+            name: 'losses'
             type: 'u64'
           },
           {
-            name: 'finalFlopPrice'
-            docs: ['Final price for Flop outcome at the end of the week']
+            name: 'marketsTraded'
+            docs: ['Markets entered while holding no open order in them']
             type: 'u64'
           },
           {
-            name: 'totalHypeShares'
-            docs: ['Total number of Hype shares issued']
+            name: 'ordersOpened'
             type: 'u64'
           },
           {
-            name: 'totalFlopShares'
-            docs: ['Total number of Flop shares issued']
-            type: 'u64'
+            name: 'firstTradeTs'
+            type: 'i64'
+          },
+          {
+            name: 'lastTradeTs'
+            type: 'i64'
+          },
+          {
+            name: 'season'
+            docs: [
+              'Season joined in this mint, every trade is scored in it until `season_end_ts`'
+            ]
+            type: 'pubkey'
+          },
+          {
+            name: 'seasonEndTs'
+            type: 'i64'
           },
           {
             name: 'padding'
             type: {
-              array: ['u8', 40]
+              array: ['u8', 32]
             }
           }
         ]
       }
     },
     {
-      name: 'stakeRewards'
+      name: 'userTrade'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'user'
-            type: 'pubkey'
+            name: 'bump'
+            type: 'u8'
           },
           {
-            name: 'mint'
+            name: 'authority'
             type: 'pubkey'
           },
           {
-            name: 'amount'
+            name: 'totalDeposits'
+            docs: [
+              'The total value of deposits the user has made (in TRD)',
+              'precision: QUOTE_PRECISION'
+            ]
             type: 'u64'
           },
           {
-            name: 'timestamp'
-            type: 'i64'
+            name: 'totalWithdraws'
+            docs: [
+              'The total value of withdrawals the user has made (in TRD)',
+              'precision: QUOTE_PRECISION'
+            ]
+            type: 'u64'
           },
           {
-            name: 'rank'
-            type: 'u16'
-          }
-        ]
-      }
-    },
-    {
-      name: 'stakeTokenArgs'
-      type: {
-        kind: 'struct'
-        fields: [
-          {
-            name: 'stakeVault'
-            type: 'string'
+            name: 'openedOrders'
+            docs: ['The number of orders the user has opened']
+            type: 'u64'
           },
           {
-            name: 'name'
-            type: 'string'
+            name: 'orders'
+            type: {
+              array: [
+                {
+                  defined: {
+                    name: 'order'
+                  }
+                },
+                10
+              ]
+            }
           },
           {
-            name: 'amount'
-            type: 'u64'
+            name: 'padding'
+            type: {
+              array: ['u8', 32]
+            }
           }
         ]
       }
     },
     {
-      name: 'stakeV2'
+      name: 'userTradeUpdate'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'bump'
-            type: 'u8'
+            name: 'user'
+            type: 'pubkey'
           },
           {
             name: 'authority'
             type: 'pubkey'
           },
           {
-            name: 'initTs'
-            type: 'i64'
-          },
-          {
-            name: 'withdrawTs'
-            type: 'i64'
+            name: 'userTrade'
+            type: 'pubkey'
           },
           {
-            name: 'claimedTs'
+            name: 'timestamp'
             type: 'i64'
-          },
-          {
-            name: 'name'
-            type: 'string'
-          },
+          }
+        ]
+      }
+    },
+    {
+      name: 'userUpdate'
+      type: {
+        kind: 'struct'
+        fields: [
           {
-            name: 'mint'
+            name: 'user'
             type: 'pubkey'
           },
           {
-            name: 'boost'
-            type: 'bool'
-          },
-          {
-            name: 'stakeVault'
+            name: 'authority'
             type: 'pubkey'
           },
           {
-            name: 'claimed'
-            type: 'u64'
+            name: 'referral'
+            type: 'pubkey'
           },
           {
-            name: 'available'
-            type: 'u64'
+            name: 'name'
+            type: 'string'
           },
           {
-            name: 'amount'
-            type: 'u64'
+            name: 'timestamp'
+            type: 'i64'
           }
         ]
       }
     },
     {
-      name: 'stakeVault'
+      name: 'vault'
       type: {
         kind: 'struct'
         fields: [
@@ -3435,201 +12155,151 @@ export type TriadProtocol = {
             type: 'pubkey'
           },
           {
-            name: 'initTs'
-            type: 'i64'
-          },
-          {
-            name: 'endTs'
-            type: 'i64'
-          },
-          {
-            name: 'amount'
-            type: 'u64'
+            name: 'name'
+            type: 'string'
           },
           {
-            name: 'amountPaid'
-            type: 'u64'
+            name: 'tokenAccount'
+            type: 'pubkey'
           },
           {
-            name: 'tokenDecimals'
-            type: 'u8'
+            name: 'tickerAddress'
+            type: 'pubkey'
           },
           {
-            name: 'nftStaked'
+            name: 'totalDeposited'
             type: 'u64'
           },
           {
-            name: 'slots'
+            name: 'totalWithdrawn'
             type: 'u64'
           },
           {
-            name: 'isLocked'
-            type: 'bool'
-          },
-          {
-            name: 'name'
-            type: 'string'
+            name: 'initTs'
+            type: 'i64'
           },
           {
-            name: 'collection'
-            type: 'string'
+            name: 'netDeposits'
+            type: 'u128'
           },
           {
-            name: 'tokenMint'
-            type: 'pubkey'
+            name: 'netWithdraws'
+            type: 'u128'
           },
           {
-            name: 'week'
-            type: 'u8'
+            name: 'longBalance'
+            type: 'u64'
           },
           {
-            name: 'tokenStaked'
+            name: 'shortBalance'
             type: 'u64'
           },
           {
-            name: 'sumAllUsers'
-            type: 'f64'
+            name: 'longPositionsOpened'
+            type: 'u64'
           },
           {
-            name: 'padding'
-            type: {
-              array: ['u8', 32]
-            }
+            name: 'shortPositionsOpened'
+            type: 'u64'
           }
         ]
       }
     },
     {
-      name: 'updateStakeVaultArgs'
+      name: 'voteArgs'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'amount'
-            type: {
-              option: 'u64'
-            }
-          },
-          {
-            name: 'isLocked'
+            name: 'direction'
             type: {
-              option: 'bool'
+              defined: {
+                name: 'orderDirection'
+              }
             }
           },
           {
-            name: 'stakeVault'
-            type: 'string'
+            name: 'votes'
+            docs: [
+              'Number of votes to cast, the first vote of each voter is free'
+            ]
+            type: 'u64'
           }
         ]
       }
     },
     {
-      name: 'user'
+      name: 'voteCast'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'ts'
-            type: 'i64'
-          },
-          {
-            name: 'authority'
+            name: 'votingMarket'
             type: 'pubkey'
           },
           {
-            name: 'bump'
-            type: 'u8'
-          },
-          {
-            name: 'referral'
+            name: 'voter'
             type: 'pubkey'
           },
           {
-            name: 'referred'
-            type: 'i64'
-          },
-          {
-            name: 'name'
-            type: 'string'
+            name: 'direction'
+            type: {
+              defined: {
+                name: 'orderDirection'
+              }
+            }
           },
           {
-            name: 'swaps'
-            type: 'i16'
+            name: 'votes'
+            type: 'u64'
           },
           {
-            name: 'swapsMade'
-            type: 'i16'
+            name: 'totalHype'
+            type: 'u64'
           },
           {
-            name: 'staked'
+            name: 'totalFlop'
             type: 'u64'
           },
           {
-            name: 'firstSwap'
+            name: 'timestamp'
             type: 'i64'
-          },
-          {
-            name: 'userTrade'
-            type: 'pubkey'
           }
         ]
       }
     },
     {
-      name: 'userPosition'
+      name: 'voteRecord'
       type: {
         kind: 'struct'
         fields: [
-          {
-            name: 'ts'
-            type: 'i64'
-          },
           {
             name: 'bump'
             type: 'u8'
           },
           {
-            name: 'totalDeposited'
-            type: 'u64'
-          },
-          {
-            name: 'totalWithdrawn'
-            type: 'u64'
-          },
-          {
-            name: 'lpShare'
-            type: 'u64'
-          },
-          {
-            name: 'totalPositions'
-            type: 'u16'
+            name: 'votingMarket'
+            type: 'pubkey'
           },
           {
-            name: 'ticker'
+            name: 'voter'
             type: 'pubkey'
           },
           {
-            name: 'authority'
-            type: 'pubkey'
+            name: 'votes'
+            type: 'u64'
           },
           {
-            name: 'positions'
+            name: 'padding'
             type: {
-              array: [
-                {
-                  defined: {
-                    name: 'position'
-                  }
-                },
-                3
-              ]
+              array: ['u8', 16]
             }
           }
         ]
       }
     },
     {
-      name: 'userTrade'
+      name: 'votingMarket'
       type: {
         kind: 'struct'
         fields: [
@@ -3642,108 +12312,136 @@ export type TriadProtocol = {
             type: 'pubkey'
           },
           {
-            name: 'totalDeposits'
-            docs: [
-              'The total value of deposits the user has made (in TRD)',
-              'precision: QUOTE_PRECISION'
-            ]
+            name: 'voteId'
+            docs: ['Unique identifier for the proposal']
             type: 'u64'
           },
           {
-            name: 'totalWithdraws'
+            name: 'question'
+            docs: ['The question proposed for the market']
+            type: {
+              array: ['u8', 80]
+            }
+          },
+          {
+            name: 'resolutionSource'
+            docs: ['Where the outcome will be verified (e.g., an URL)']
+            type: {
+              array: ['u8', 80]
+            }
+          },
+          {
+            name: 'mint'
             docs: [
-              'The total value of withdrawals the user has made (in TRD)',
-              'precision: QUOTE_PRECISION'
+              'Mint $TRD token, used for paid votes and as the market collateral'
             ]
+            type: 'pubkey'
+          },
+          {
+            name: 'votingStart'
+            type: 'i64'
+          },
+          {
+            name: 'votingEnd'
+            type: 'i64'
+          },
+          {
+            name: 'marketStart'
+            docs: ['Schedule of the market once promoted']
+            type: 'i64'
+          },
+          {
+            name: 'marketEnd'
+            type: 'i64'
+          },
+          {
+            name: 'resolveTs'
+            type: 'i64'
+          },
+          {
+            name: 'totalHype'
+            docs: ['Total Hype votes']
             type: 'u64'
           },
           {
-            name: 'openedOrders'
-            docs: ['The number of orders the user has opened']
+            name: 'totalFlop'
+            docs: ['Total Flop votes']
             type: 'u64'
           },
           {
-            name: 'orders'
-            type: {
-              array: [
-                {
-                  defined: {
-                    name: 'order'
-                  }
-                },
-                10
-              ]
-            }
+            name: 'minVotes'
+            type: 'u64'
+          },
+          {
+            name: 'marketId'
+            docs: ['Market created from this proposal, only set once promoted']
+            type: 'u64'
+          },
+          {
+            name: 'isPromoted'
+            type: 'bool'
           },
           {
             name: 'padding'
             type: {
-              array: ['u8', 32]
+              array: ['u8', 64]
             }
           }
         ]
       }
     },
     {
-      name: 'vault'
+      name: 'votingMarketUpdate'
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'bump'
-            type: 'u8'
-          },
-          {
-            name: 'authority'
+            name: 'votingMarket'
             type: 'pubkey'
           },
           {
-            name: 'name'
-            type: 'string'
-          },
-          {
-            name: 'tokenAccount'
-            type: 'pubkey'
+            name: 'voteId'
+            type: 'u64'
           },
           {
-            name: 'tickerAddress'
+            name: 'authority'
             type: 'pubkey'
           },
           {
-            name: 'totalDeposited'
-            type: 'u64'
+            name: 'question'
+            type: 'string'
           },
           {
-            name: 'totalWithdrawn'
+            name: 'marketId'
             type: 'u64'
           },
           {
-            name: 'initTs'
+            name: 'votingEnd'
             type: 'i64'
           },
           {
-            name: 'netDeposits'
-            type: 'u128'
+            name: 'marketStart'
+            type: 'i64'
           },
           {
-            name: 'netWithdraws'
-            type: 'u128'
+            name: 'marketEnd'
+            type: 'i64'
           },
           {
-            name: 'longBalance'
+            name: 'totalHype'
             type: 'u64'
           },
           {
-            name: 'shortBalance'
+            name: 'totalFlop'
             type: 'u64'
           },
           {
-            name: 'longPositionsOpened'
-            type: 'u64'
+            name: 'isPromoted'
+            type: 'bool'
           },
           {
-            name: 'shortPositionsOpened'
-            type: 'u64'
+            name: 'timestamp'
+            type: 'i64'
           }
         ]
       }
@@ -3761,6 +12459,9 @@ export type TriadProtocol = {
           },
           {
             name: 'flop'
+          },
+          {
+            name: 'void'
           }
         ]
       }
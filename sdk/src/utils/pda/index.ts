@@ -11,6 +11,13 @@ export const getUserPDA = (programId: PublicKey, wallet: PublicKey) => {
   )[0]
 }
 
+export const getEventAuthorityPDA = (programId: PublicKey) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('__event_authority')],
+    programId
+  )[0]
+}
+
 export const getTickerPDA = (programId: PublicKey, tickerName: string) => {
   return PublicKey.findProgramAddressSync(
     [Buffer.from('ticker'), Buffer.from(tickerName)],
@@ -14,3 +14,32 @@ export const getUserTradePDA = (programId: PublicKey, wallet: PublicKey) => {
     programId
   )[0]
 }
+
+export const getMarketHistoryPDA = (programId: PublicKey, market: PublicKey) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('market_history'), market.toBuffer()],
+    programId
+  )[0]
+}
+
+export const getUserStatsPDA = (
+  programId: PublicKey,
+  wallet: PublicKey,
+  mint: PublicKey
+) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('user_stats'), wallet.toBuffer(), mint.toBuffer()],
+    programId
+  )[0]
+}
+
+export const getSeasonScorePDA = (
+  programId: PublicKey,
+  season: PublicKey,
+  wallet: PublicKey
+) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('season_score'), season.toBuffer(), wallet.toBuffer()],
+    programId
+  )[0]
+}